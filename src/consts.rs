@@ -11,5 +11,9 @@ pub const LOCAL_MESSAGE_NUMBER_MASK: u8 = 0x0F;
 pub const _FIELD_DEFINITION_ARCHITECTURE: u8 = 0b10_000_000;
 pub const FIELD_DEFINITION_BASE_NUMBER: u8 = 0b00_011_111;
 
-pub const COORD_SEMICIRCLES_CALC: f32 = (180f64 / (std::u32::MAX as u64 / 2 + 1) as f64) as f32;
 pub const PSEUDO_EPOCH: u32 = 631_065_600;
+pub const MIN_DATE_TIME: u32 = 0x1000_0000; // Times below this are seconds since the device was turned on, not since the epoch
+pub const MAX_UTC_OFFSET: i32 = 14 * 3600; // No time zone is further from UTC than this, in seconds
+
+pub const MFG_RANGE_MIN: u16 = 0xFF00; // Global message numbers from here on are manufacturer specific
+pub const MFG_RANGE_MAX: u16 = 0xFFFE;
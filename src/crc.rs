@@ -0,0 +1,79 @@
+use crate::error::FitError;
+use crate::types::file_header::FileHeader;
+
+//////////
+//// CRC
+//////////
+
+const CRC_TABLE: [u16; 16] = [
+    0x0000, 0xCC01, 0xD801, 0x1400, 0xF001, 0x3C00, 0x2800, 0xE401, 0xA001, 0x6C00, 0x7800, 0xB401,
+    0x5000, 0x9C01, 0x8801, 0x4400,
+];
+
+/// Adds a single byte to a running FIT CRC-16.
+pub fn crc_byte(crc: u16, byte: u8) -> u16 {
+    // checksum of the lower four bits
+    let tmp = CRC_TABLE[usize::from(crc & 0xF)];
+    let crc = (crc >> 4) & 0x0FFF;
+    let crc = crc ^ tmp ^ CRC_TABLE[usize::from(byte & 0xF)];
+
+    // checksum of the upper four bits
+    let tmp = CRC_TABLE[usize::from(crc & 0xF)];
+    let crc = (crc >> 4) & 0x0FFF;
+    crc ^ tmp ^ CRC_TABLE[usize::from((byte >> 4) & 0xF)]
+}
+
+/// Calculates the FIT CRC-16 of `data`.
+pub fn crc16(data: &[u8]) -> u16 {
+    data.iter().fold(0, |crc, b| crc_byte(crc, *b))
+}
+
+/// Checks both the header CRC and the file CRC of a complete FIT file, and of any files chained
+/// after it, without decoding any of their records.
+pub fn verify(data: &[u8]) -> Result<(), FitError> {
+    let mut data = data;
+    // several FIT files may be chained together, each with its own header and CRC
+    loop {
+        let fh = FileHeader::new(&mut &data[..])?;
+        fh.check_crc()?;
+        let end = fh.data_end() as usize;
+        if data.len() < end + 2 {
+            return Err(FitError::TruncatedRecord);
+        }
+        let expected = u16::from_le_bytes([data[end], data[end + 1]]);
+        let calculated = crc16(&data[..end]);
+        if expected != calculated {
+            return Err(FitError::CrcMismatch {
+                expected,
+                calculated,
+            });
+        }
+        data = &data[end + 2..];
+        if data.is_empty() {
+            return Ok(());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_calculates_crc() {
+        assert_eq!(crc16(b"123456789"), 0xBB3D);
+        assert_eq!(crc16(&[]), 0);
+    }
+    #[test]
+    fn it_calculates_header_crc() {
+        let a = [14, 16, 116, 6, 51, 92, 1, 0, 46, 70, 73, 84];
+        assert_eq!(crc16(&a), 3797);
+    }
+    #[test]
+    fn it_includes_the_crc_itself() {
+        // appending a CRC to the data it was calculated over always gives a CRC of zero
+        let mut a = b"123456789".to_vec();
+        a.extend_from_slice(&crc16(&a).to_le_bytes());
+        assert_eq!(crc16(&a), 0);
+    }
+}
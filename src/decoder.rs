@@ -0,0 +1,863 @@
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::io::{self, Read};
+
+use copyless::VecHelper;
+use fitsdk::{
+    get_field_string_value_fn, get_field_type_fn, get_message_timestamp_field, get_message_type,
+    FieldType, MessageType,
+};
+
+use crate::consts::*;
+use crate::developer_fields::{DeveloperDataId, DeveloperFieldDescription};
+use crate::error::FitError;
+use crate::io::*;
+use crate::options::DecodeOptions;
+use crate::profile::{accumulated_from, components, field_info, field_num, subfields, SubField};
+use crate::types::data_field::DataField;
+use crate::types::definition_record::DefinitionRecord;
+use crate::types::dev_data_field::DevDataField;
+use crate::types::header_byte::HeaderByte;
+use crate::types::message::Message;
+use crate::types::record::Record;
+use crate::types::timestamp::LocalTime;
+use crate::Value;
+
+//////////
+//// Decoder
+//////////
+
+/// The state that has to be carried from one record to the next while decoding a file.
+/// Nothing is changed until a record has been read completely, so a record that fails
+/// part way through can be retried once more data is available.
+#[derive(Default)]
+pub(crate) struct Decoder {
+    definitions: [Option<DefinitionRecord>; 16],
+    developer_fields: HashMap<(u8, u8), DeveloperFieldDescription>,
+    developer_data_ids: HashMap<u8, DeveloperDataId>,
+    /// The last full timestamp seen, which compressed timestamps count on from.
+    last_timestamp: Option<u32>,
+    utc_offset: Option<i32>,
+    accumulators: HashMap<(u16, usize), Accumulator>,
+    /// Holds the bytes of each data record when they aren't kept in the `Record`.
+    record_buf: Vec<u8>,
+    options: DecodeOptions,
+}
+impl Decoder {
+    pub fn options(&self) -> &DecodeOptions {
+        &self.options
+    }
+    pub fn options_mut(&mut self) -> &mut DecodeOptions {
+        &mut self.options
+    }
+    /// Forgets everything learned from the records decoded so far, ready for the start of
+    /// another file.
+    pub fn reset(&mut self) {
+        let options = std::mem::take(&mut self.options);
+        *self = Decoder {
+            options,
+            ..Decoder::default()
+        };
+    }
+    /// The definition in use for each local message number.
+    pub fn definitions(&self) -> &[Option<DefinitionRecord>] {
+        &self.definitions
+    }
+    /// The application that defined the developer fields with the given data index.
+    pub fn developer_data_id(&self, developer_data_index: u8) -> Option<&DeveloperDataId> {
+        self.developer_data_ids.get(&developer_data_index)
+    }
+    /// The offset from UTC of the local times in this file, taken from the last message that
+    /// recorded both a `timestamp` and a `local_timestamp`.
+    pub fn utc_offset(&self) -> Option<i32> {
+        self.utc_offset
+    }
+    /// Reads a single record, returning the message it contains if it's a data record that
+    /// decodes to a known message.
+    pub fn decode_record<R>(
+        &mut self,
+        map: &mut R,
+        offset: u64,
+    ) -> Result<Option<Message>, FitError>
+    where
+        R: Read,
+    {
+        let record = self.read_record(map, offset, false)?;
+        self.decode_message(record)
+    }
+    /// Reads a single record starting at `offset`, remembering the definition if it's a
+    /// definition record. The fields of a data record are only decoded by their base type,
+    /// and its bytes are only kept in `raw_bytes` if asked for.
+    pub fn read_record<R>(
+        &mut self,
+        map: &mut R,
+        offset: u64,
+        keep_raw_bytes: bool,
+    ) -> Result<Record, FitError>
+    where
+        R: Read,
+    {
+        let h = HeaderByte::new(map)?;
+        let local_num = h.local_num;
+        if h.definition {
+            let definition = DefinitionRecord::new(map, h.dev_fields)?;
+            self.definitions[usize::from(local_num)] = Some(definition.clone());
+            return Ok(Record::Definition {
+                local_num,
+                offset,
+                definition,
+            });
+        }
+
+        // without a definition there's no way to tell how long this record is
+        let definition = match &self.definitions[usize::from(local_num)] {
+            Some(def) => def,
+            None => return Err(FitError::UndefinedLocalMessage(local_num)),
+        };
+        let size = definition
+            .field_definitions
+            .iter()
+            .map(|fd| usize::from(fd.size))
+            .chain(
+                definition
+                    .developer_fields
+                    .iter()
+                    .flatten()
+                    .map(|df| usize::from(df.size)),
+            )
+            .sum();
+        let mut bytes = if keep_raw_bytes {
+            Vec::new()
+        } else {
+            std::mem::take(&mut self.record_buf)
+        };
+        bytes.clear();
+        bytes.resize(size, 0);
+        map.read_exact(&mut bytes)?;
+        let map = &mut &bytes[..];
+
+        // read all the values for this record type's defined fields
+        let mut fields = Vec::with_capacity(definition.field_definitions.len());
+        let mut field_error = None;
+        for fd in definition.field_definitions.iter() {
+            match read_next_field(fd.size, fd.base_type, definition.endianness, map) {
+                Ok(Some(data)) => {
+                    let mut field = DataField::new(fd.definition_number, data);
+                    field.invalid_elements = invalid_elements(fd.base_type, &field.value);
+                    fields.alloc().init(field);
+                }
+                Ok(None) if self.options.invalid_values => {
+                    fields
+                        .alloc()
+                        .init(DataField::new(fd.definition_number, Value::Invalid));
+                }
+                Ok(None) => (),
+                // the bytes were skipped so keep reading, but don't return a partial record
+                Err(e) if e.is_recoverable() => field_error = Some(e),
+                Err(e) => return Err(e),
+            }
+        }
+
+        // if this record contains developer-defined fields read those too, skipping any
+        // that haven't been described
+        let dev_fields = match &definition.developer_fields {
+            None => None,
+            Some(dev_field_defs) => {
+                let mut dev_values = Vec::with_capacity(dev_field_defs.len());
+                for df in dev_field_defs.iter() {
+                    let key = (df.developer_data_index, df.field_number);
+                    let description = match self.developer_fields.get(&key) {
+                        Some(d) => d,
+                        None => {
+                            skip_bytes(map, df.size)?;
+                            continue;
+                        }
+                    };
+                    let base_type = description.fit_base_type & FIELD_DEFINITION_BASE_NUMBER;
+                    match read_next_field(df.size, base_type, definition.endianness, map) {
+                        Ok(Some(v)) => dev_values.push(DevDataField::new(description, v)),
+                        Ok(None) if self.options.invalid_values => {
+                            dev_values.push(DevDataField::new(description, Value::Invalid));
+                        }
+                        Ok(None) => (),
+                        Err(e) if e.is_recoverable() => field_error = Some(e),
+                        Err(e) => return Err(e),
+                    }
+                }
+                Some(dev_values)
+            }
+        };
+        if let Some(e) = field_error {
+            return Err(e);
+        }
+
+        let raw_bytes = if keep_raw_bytes {
+            bytes
+        } else {
+            self.record_buf = bytes;
+            Vec::new()
+        };
+        Ok(Record::Data {
+            header: h,
+            local_num,
+            global_message_number: definition.global_message_number,
+            offset,
+            raw_bytes,
+            fields,
+            dev_fields,
+        })
+    }
+    /// Turns a record into the message it contains, interpreting its fields using the profile
+    /// and remembering whatever later records depend on, such as developer field descriptions.
+    pub fn decode_message(&mut self, record: Record) -> Result<Option<Message>, FitError> {
+        let (h, global_message_number, fields, dev_fields) = match record {
+            Record::Definition { .. } => return Ok(None),
+            Record::Data {
+                header,
+                global_message_number,
+                fields,
+                dev_fields,
+                ..
+            } => (header, global_message_number, fields, dev_fields),
+        };
+        let message_type = get_message_type(global_message_number);
+        let unknown = message_type == MessageType::None;
+        let mut values = fields;
+        let dev_fields = dev_fields.map(|mut dev_values| {
+            if self.options.scale {
+                for d in dev_values.iter_mut() {
+                    if let Some(description) =
+                        self.developer_fields.get(&(d.data_index, d.field_num))
+                    {
+                        let value = std::mem::replace(&mut d.value, Value::Invalid);
+                        d.value = description.apply_scale(value);
+                    }
+                }
+            }
+            dev_values
+        });
+
+        // compressed timestamp headers and timestamp_16 fields only hold the low bits of the
+        // time, so the full timestamp is rebuilt from the last one seen, if there's been one
+        let timestamp_field = get_message_timestamp_field(message_type);
+        let timestamp_16_field = field_num(global_message_number, "timestamp_16");
+        let timestamp_16 = values.iter().find_map(|v| match v.value {
+            Value::U16(t) if Some(v.field_num) == timestamp_16_field => Some(t),
+            _ => None,
+        });
+        let timestamp = match h.compressed_timestamp() {
+            Some(time_offset) => self
+                .last_timestamp
+                .and_then(|last| expand_timestamp(last, time_offset)),
+            None => timestamp_field
+                .and_then(|n| values.iter().find(|v| v.field_num == n))
+                .and_then(|v| match v.value {
+                    Value::U32(t) => Some(t),
+                    _ => None,
+                })
+                .or_else(|| {
+                    let last = self.last_timestamp?;
+                    timestamp_16.and_then(|t| expand_timestamp_16(last, t))
+                }),
+        };
+        if timestamp.is_some() {
+            self.last_timestamp = timestamp;
+        }
+
+        // messages like activity record the local time alongside the timestamp, which is the
+        // only way to tell which time zone the local times in the file are in
+        let local_timestamp_field = field_num(global_message_number, "local_timestamp");
+        let local_timestamp = values.iter().find_map(|v| match v.value {
+            Value::U32(t) if Some(v.field_num) == local_timestamp_field => Some(t),
+            _ => None,
+        });
+        if let (Some(t), Some(local)) = (timestamp, local_timestamp) {
+            if t >= MIN_DATE_TIME && local >= MIN_DATE_TIME {
+                // a difference bigger than any time zone means one of the clocks was wrong
+                let offset = i32::try_from(i64::from(local) - i64::from(t)).ok();
+                if let Some(o) = offset.filter(|o| o.abs() <= MAX_UTC_OFFSET) {
+                    self.utc_offset = Some(o);
+                }
+            }
+        }
+
+        // if this is a developer field definition
+        if message_type == MessageType::FieldDescription {
+            let d = DeveloperFieldDescription::new(values)?;
+            self.developer_fields
+                .insert((d.developer_data_index, d.field_definition_number), d);
+            return Ok(None);
+        }
+
+        if values.is_empty() || (unknown && !self.options.unknown_messages) {
+            return Ok(None);
+        }
+
+        // the profile doesn't describe this message, so its values can't be interpreted
+        if unknown {
+            return Ok(Some(Message {
+                values,
+                kind: message_type,
+                global_message_number,
+                dev_values: dev_fields,
+                segment: 0,
+            }));
+        }
+
+        // remember which application each developer data index belongs to
+        if message_type == MessageType::DeveloperDataId {
+            let d = DeveloperDataId::new(&values)?;
+            self.developer_data_ids.insert(d.developer_data_index, d);
+        }
+
+        // check each value in case the raw value needs further processing
+        let fields = get_field_type_fn(message_type);
+        if self.options.expand_components {
+            let expanded =
+                expand_components(global_message_number, &values, &mut self.accumulators);
+            // an invalid field is replaced by the value its component gives it
+            values.retain(|v| {
+                v.value != Value::Invalid || !expanded.iter().any(|e| e.field_num == v.field_num)
+            });
+            values.extend(expanded);
+        }
+        let resolved: Vec<_> = values
+            .iter()
+            .map(|v| resolve_subfield(global_message_number, v.field_num, &values))
+            .collect();
+        for (v, subfield) in values.iter_mut().zip(resolved) {
+            match subfield {
+                Some(s) => apply_subfield(v, s, &self.options),
+                None => process_value(v, global_message_number, fields, &self.options),
+            }
+        }
+
+        // local times are given the offset from UTC known so far, which includes this message's
+        for v in values.iter_mut() {
+            if let Value::LocalTime(ref mut t) = v.value {
+                t.utc_offset = self.utc_offset;
+            }
+        }
+
+        // developer fields can stand in for a native field the device didn't record itself
+        if self.options.native_developer_fields {
+            for d in dev_fields.iter().flatten() {
+                let native = self
+                    .developer_fields
+                    .get(&(d.data_index, d.field_num))
+                    .and_then(|desc| desc.native_field(global_message_number));
+                if let Some(n) = native {
+                    if !values.iter().any(|v| v.field_num == n) {
+                        values.alloc().init(DataField::new(n, d.value.clone()));
+                    }
+                }
+            }
+        }
+
+        // give messages that only had a compressed timestamp their full timestamp
+        if let (Some(t), Some(n)) = (timestamp, timestamp_field) {
+            if !values.iter().any(|v| v.field_num == n) {
+                values.alloc().init(DataField::new(n, time_value(t)));
+            }
+        }
+
+        // name each field the profile knows about
+        for v in values.iter_mut().filter(|v| v.name.is_none()) {
+            if let Some(info) = field_info(global_message_number, v.field_num) {
+                v.name = Some(info.name);
+                if v.subfield.is_none() {
+                    v.units = Some(info.units).filter(|u| !u.is_empty());
+                }
+            }
+        }
+
+        // if any values were invalid we have a vec that's now too long
+        values.shrink_to_fit();
+        Ok(Some(Message {
+            values,
+            kind: message_type,
+            global_message_number,
+            dev_values: dev_fields,
+            segment: 0,
+        }))
+    }
+}
+
+/// Unpacks the fields that the profile says are packed into the bits of other fields. Each
+/// one is converted to the raw value its destination field would have had, so it can be
+/// processed like any other field, and fields recorded directly take precedence.
+pub(crate) fn expand_components(
+    global_message_number: u16,
+    values: &[DataField],
+    accumulators: &mut HashMap<(u16, usize), Accumulator>,
+) -> Vec<DataField> {
+    // a field that's recorded in full restarts the total its components are accumulated on
+    for v in values {
+        let c = match accumulated_from(global_message_number, v.field_num) {
+            Some(c) => c,
+            None => continue,
+        };
+        let raw = match &v.value {
+            Value::ArrU32(a) => a.last().copied(),
+            v => raw_u32(v),
+        };
+        if let Some(raw) = raw {
+            let (scale, offset) = scale_and_offset(global_message_number, v.field_num);
+            let value = f64::from(raw) / scale.unwrap_or(1.0) - offset.unwrap_or(0.0);
+            let total = ((value + c.offset) * c.scale).round() as u64;
+            accumulators.insert(
+                (global_message_number, v.field_num),
+                Accumulator::new(total, c.bits),
+            );
+        }
+    }
+
+    // a component can have components of its own, e.g. the speed in compressed_speed_distance
+    // expands to enhanced_speed, so the fields each pass produces are expanded in turn
+    let mut expanded: Vec<DataField> = Vec::new();
+    let mut pending = values.to_vec();
+    while !pending.is_empty() {
+        let mut produced: Vec<DataField> = Vec::new();
+        for v in &pending {
+            let components = match resolve_subfield(global_message_number, v.field_num, values) {
+                Some(s) => s.components,
+                None => components(global_message_number, v.field_num),
+            };
+            let bytes = match v.value.to_le_bytes() {
+                Some(b) if !components.is_empty() => b,
+                _ => continue,
+            };
+            let mut start = 0;
+            for c in components {
+                let bits = match take_bits(&bytes, start, c.bits) {
+                    Some(b) => b,
+                    None => break,
+                };
+                start += usize::from(c.bits);
+                // a field recorded as invalid doesn't count as recorded
+                if values
+                    .iter()
+                    .chain(&expanded)
+                    .any(|v| v.field_num == c.field_num && v.value != Value::Invalid)
+                {
+                    continue;
+                }
+                let bits = if c.accumulate {
+                    let key = (global_message_number, c.field_num);
+                    accumulators
+                        .entry(key)
+                        .or_insert_with(|| Accumulator::new(bits, c.bits))
+                        .add(bits, c.bits)
+                } else {
+                    bits
+                };
+
+                let value = bits as f64 / c.scale - c.offset;
+                let (scale, offset) = scale_and_offset(global_message_number, c.field_num);
+                let raw = (value + offset.unwrap_or(0.0)) * scale.unwrap_or(1.0);
+                let raw = raw_value(c.base_type, raw.round() as i64);
+                match produced.iter_mut().find(|e| e.field_num == c.field_num) {
+                    Some(e) => e.value.push(raw),
+                    None => produced.push(DataField::new(c.field_num, raw)),
+                }
+            }
+        }
+        expanded.extend(produced.iter().cloned());
+        pending = produced;
+    }
+    expanded
+}
+
+/// The running total of a field that's sent as a rolling counter of only a few bits.
+#[derive(Debug)]
+pub(crate) struct Accumulator {
+    last: u64,
+    total: u64,
+}
+impl Accumulator {
+    fn new(total: u64, bits: u8) -> Self {
+        Self {
+            last: total & mask(bits),
+            total,
+        }
+    }
+    /// Adds on how far the counter has moved since it was last seen, allowing for it having
+    /// wrapped around.
+    fn add(&mut self, counter: u64, bits: u8) -> u64 {
+        self.total += counter.wrapping_sub(self.last) & mask(bits);
+        self.last = counter;
+        self.total
+    }
+}
+
+fn mask(bits: u8) -> u64 {
+    (1 << bits) - 1
+}
+
+/// Finds the subfield that gives a field its meaning, based on the raw values of the other
+/// fields in the same message.
+pub(crate) fn resolve_subfield(
+    global_message_number: u16,
+    field_num: usize,
+    values: &[DataField],
+) -> Option<&'static SubField> {
+    subfields(global_message_number, field_num)
+        .iter()
+        .find(|s| {
+            s.refs.iter().any(|(n, raw)| {
+                values
+                    .iter()
+                    .any(|v| v.field_num == *n && raw_u32(&v.value) == Some(*raw))
+            })
+        })
+}
+
+/// Gives a field the units and scale of the subfield it's been resolved to. The field keeps
+/// its own name, so it can still be found by either.
+fn apply_subfield(v: &mut DataField, s: &SubField, options: &DecodeOptions) {
+    v.subfield = Some(s.name);
+    v.units = Some(s.units).filter(|u| !u.is_empty());
+    if let Some(kind) = s.kind {
+        let name = raw_u32(&v.value).and_then(|k| get_field_string_value_fn(kind, k as usize));
+        if let Some(t) = name {
+            v.value = Value::Enum(t);
+        }
+    } else if options.scale && (s.scale.is_some() || s.offset.is_some()) {
+        scale_field(v, s.scale.unwrap_or(1.0), s.offset.unwrap_or(0.0));
+    }
+}
+
+/// Converts a field to its units, leaving any invalid elements of an array as NaN rather
+/// than scaling the invalid value itself.
+fn scale_field(v: &mut DataField, scale: f64, offset: f64) {
+    if let Some(scaled) = v.value.scale_offset(scale, offset) {
+        v.value = scaled;
+    }
+    if let Value::ArrF64(a) = &mut v.value {
+        for i in v.invalid_elements.iter() {
+            if let Some(x) = a.get_mut(*i) {
+                *x = f64::NAN;
+            }
+        }
+    }
+}
+
+/// The positions of the elements of an array that hold the invalid value for `base_type`.
+fn invalid_elements(base_type: u8, value: &Value) -> Vec<usize> {
+    fn positions<T: PartialEq>(values: &[T], invalid: T) -> Vec<usize> {
+        values
+            .iter()
+            .enumerate()
+            .filter(|(_, v)| **v == invalid)
+            .map(|(i, _)| i)
+            .collect()
+    }
+    match (base_type, value) {
+        (0, Value::ArrU8(v)) | (2, Value::ArrU8(v)) => positions(v, 0xFF),
+        (1, Value::ArrI8(v)) => positions(v, 0x7F),
+        (3, Value::ArrI16(v)) => positions(v, 0x7FFF),
+        (4, Value::ArrU16(v)) => positions(v, 0xFFFF),
+        (5, Value::ArrI32(v)) => positions(v, 0x7FFF_FFFF),
+        (6, Value::ArrU32(v)) => positions(v, 0xFFFF_FFFF),
+        (10, Value::ArrU8(v)) => positions(v, 0x00),
+        (11, Value::ArrU16(v)) => positions(v, 0x0000),
+        (12, Value::ArrU32(v)) => positions(v, 0x0000_0000),
+        (14, Value::ArrI64(v)) => positions(v, 0x7FFF_FFFF_FFFF_FFFF),
+        (15, Value::ArrU64(v)) => positions(v, 0xFFFF_FFFF_FFFF_FFFF),
+        (16, Value::ArrU64(v)) => positions(v, 0x0000_0000_0000_0000),
+        // floats are invalid when all their bits are set, which isn't a value they compare to
+        (8, Value::ArrF32(v)) => positions(
+            &v.iter().map(|x| x.to_bits()).collect::<Vec<_>>(),
+            0xFFFF_FFFF,
+        ),
+        (9, Value::ArrF64(v)) => positions(
+            &v.iter().map(|x| x.to_bits()).collect::<Vec<_>>(),
+            0xFFFF_FFFF_FFFF_FFFF,
+        ),
+        _ => Vec::new(),
+    }
+}
+
+fn raw_u32(v: &Value) -> Option<u32> {
+    match v {
+        Value::U8(v) => Some(u32::from(*v)),
+        Value::U16(v) => Some(u32::from(*v)),
+        Value::U32(v) => Some(*v),
+        _ => None,
+    }
+}
+
+/// Reads `bits` bits starting `start` bits into `bytes`, counting from the least significant
+/// bit of the first byte.
+fn take_bits(bytes: &[u8], start: usize, bits: u8) -> Option<u64> {
+    if start + usize::from(bits) > bytes.len() * 8 {
+        return None;
+    }
+    let mut v = 0;
+    for i in 0..usize::from(bits) {
+        let bit = start + i;
+        if (bytes[bit / 8] >> (bit % 8)) & 1 == 1 {
+            v |= 1 << i;
+        }
+    }
+    Some(v)
+}
+
+fn raw_value(base_type: u8, v: i64) -> Value {
+    match base_type {
+        0 | 2 | 10 | 13 => Value::U8(v as u8),
+        1 => Value::I8(v as i8),
+        3 => Value::I16(v as i16),
+        4 | 11 => Value::U16(v as u16),
+        5 => Value::I32(v as i32),
+        14 => Value::I64(v),
+        15 | 16 => Value::U64(v as u64),
+        _ => Value::U32(v as u32),
+    }
+}
+
+/// Rebuilds a full timestamp from the offset in a compressed timestamp header, which counts
+/// on from the low 5 bits of the last timestamp and rolls over every 32 seconds. There's no
+/// full timestamp if it rolls over past the largest one a u32 can hold.
+pub(crate) fn expand_timestamp(last_timestamp: u32, time_offset: u8) -> Option<u32> {
+    let time_offset = u32::from(time_offset);
+    let timestamp = (last_timestamp & COMPRESSED_HEADER_LAST_TIMESTAMP_MASK) + time_offset;
+    if time_offset < last_timestamp & u32::from(COMPRESSED_HEADER_TIME_OFFSET_MASK) {
+        timestamp.checked_add(COMPRESSED_HEADER_TIME_OFFSET_ROLLOVER)
+    } else {
+        Some(timestamp)
+    }
+}
+
+/// Rebuilds a full timestamp from a timestamp_16 field, which holds the low 16 bits of the
+/// time and rolls over about every 18 hours. There's no full timestamp that late if it's past
+/// the largest one a u32 can hold.
+pub(crate) fn expand_timestamp_16(last_timestamp: u32, timestamp_16: u16) -> Option<u32> {
+    let elapsed = timestamp_16.wrapping_sub(last_timestamp as u16);
+    last_timestamp.checked_add(u32::from(elapsed))
+}
+
+/// A FIT date_time, which counts from the FIT epoch unless it's small enough to be a system
+/// time counting from when the device was turned on. Times too late to count from the Unix
+/// epoch in a u32 are invalid.
+fn time_value(t: u32) -> Value {
+    if t < MIN_DATE_TIME {
+        Value::SystemTime(t)
+    } else {
+        t.checked_add(PSEUDO_EPOCH)
+            .map_or(Value::Invalid, Value::Time)
+    }
+}
+
+pub(crate) fn read_next_field<R>(
+    size: u8,
+    base_type: u8,
+    endianness: Endianness,
+    map: &mut R,
+) -> Result<Option<Value>, FitError>
+where
+    R: Read,
+{
+    let e = endianness;
+    let value = match base_type {
+        // enum / uint8
+        0 | 2 => to_value(
+            read_values(map, size, 1, 0xFF, read_u8)?,
+            Value::U8,
+            Value::ArrU8,
+        ),
+        // sint8
+        1 => to_value(
+            read_values(map, size, 1, 0x7F, read_i8)?,
+            Value::I8,
+            Value::ArrI8,
+        ),
+        // sint16
+        3 => to_value(
+            read_values(map, size, 2, 0x7FFF, |m| read_i16(m, e))?,
+            Value::I16,
+            Value::ArrI16,
+        ),
+        // uint16
+        4 => to_value(
+            read_values(map, size, 2, 0xFFFF, |m| read_u16(m, e))?,
+            Value::U16,
+            Value::ArrU16,
+        ),
+        // sint32
+        5 => to_value(
+            read_values(map, size, 4, 0x7FFF_FFFF, |m| read_i32(m, e))?,
+            Value::I32,
+            Value::ArrI32,
+        ),
+        // uint32
+        6 => to_value(
+            read_values(map, size, 4, 0xFFFF_FFFF, |m| read_u32(m, e))?,
+            Value::U32,
+            Value::ArrU32,
+        ),
+        7 => {
+            // string, which is invalid if it's nothing but NULs
+            let mut buf = read_bytes(map, size)?;
+            buf.retain(|b| *b != 0x00);
+            String::from_utf8(buf)
+                .ok()
+                .filter(|s| !s.is_empty())
+                .map(Value::String)
+        }
+        // float32
+        8 => to_value(
+            read_values(map, size, 4, 0xFFFF_FFFF, |m| read_u32(m, e))?,
+            |v| Value::F32(f32::from_bits(v)),
+            |v| Value::ArrF32(v.into_iter().map(f32::from_bits).collect()),
+        ),
+        // float64
+        9 => to_value(
+            read_values(map, size, 8, 0xFFFF_FFFF_FFFF_FFFF, |m| read_u64(m, e))?,
+            |v| Value::F64(f64::from_bits(v)),
+            |v| Value::ArrF64(v.into_iter().map(f64::from_bits).collect()),
+        ),
+        // uint8z
+        10 => to_value(
+            read_values(map, size, 1, 0x00, read_u8)?,
+            Value::U8,
+            Value::ArrU8,
+        ),
+        // uint16z
+        11 => to_value(
+            read_values(map, size, 2, 0x0000, |m| read_u16(m, e))?,
+            Value::U16,
+            Value::ArrU16,
+        ),
+        // uint32z
+        12 => to_value(
+            read_values(map, size, 4, 0x0000_0000, |m| read_u32(m, e))?,
+            Value::U32,
+            Value::ArrU32,
+        ),
+        // byte
+        13 => to_value(
+            read_values(map, size, 1, 0xFF, read_u8)?,
+            Value::U8,
+            Value::Bytes,
+        ),
+        // sint64
+        14 => to_value(
+            read_values(map, size, 8, 0x7FFF_FFFF_FFFF_FFFF, |m| read_i64(m, e))?,
+            Value::I64,
+            Value::ArrI64,
+        ),
+        // uint64
+        15 => to_value(
+            read_values(map, size, 8, 0xFFFF_FFFF_FFFF_FFFF, |m| read_u64(m, e))?,
+            Value::U64,
+            Value::ArrU64,
+        ),
+        // uint64z
+        16 => to_value(
+            read_values(map, size, 8, 0x0000_0000_0000_0000, |m| read_u64(m, e))?,
+            Value::U64,
+            Value::ArrU64,
+        ),
+        _ => {
+            skip_bytes(map, size)?;
+            return Err(FitError::UnknownBaseType(base_type));
+        }
+    };
+    Ok(value)
+}
+
+/// Reads a field of `size` bytes as values of `width` bytes each. Invalid elements are kept
+/// so the positions in an array still line up, unless every one of them is invalid.
+fn read_values<R, T, F>(
+    map: &mut R,
+    size: u8,
+    width: u8,
+    invalid: T,
+    read: F,
+) -> Result<Option<Vec<T>>, FitError>
+where
+    R: Read,
+    T: PartialEq,
+    F: Fn(&mut R) -> io::Result<T>,
+{
+    let mut values = Vec::with_capacity(usize::from(size / width));
+    for _ in 0..size / width {
+        values.push(read(map)?);
+    }
+    skip_bytes(map, size % width)?;
+    if values.iter().all(|v| *v == invalid) {
+        Ok(None)
+    } else {
+        Ok(Some(values))
+    }
+}
+
+fn to_value<T, F, G>(values: Option<Vec<T>>, one: F, many: G) -> Option<Value>
+where
+    F: Fn(T) -> Value,
+    G: Fn(Vec<T>) -> Value,
+{
+    values.map(|mut v| {
+        if v.len() == 1 {
+            one(v.remove(0))
+        } else {
+            many(v)
+        }
+    })
+}
+
+pub(crate) fn process_value(
+    v: &mut DataField,
+    global_message_number: u16,
+    fields: fitsdk::MatchFieldTypeFn,
+    options: &DecodeOptions,
+) {
+    match fields(v.field_num) {
+        FieldType::Coordinates if !options.coordinates => (),
+        FieldType::Coordinates => {
+            // semicircles to degrees, in f64 as an f32 would lose over a metre of precision
+            if let Value::I32(inner) = v.value {
+                v.value = Value::F64(f64::from(inner) * (180.0 / 2f64.powi(31)));
+            }
+        }
+        FieldType::Timestamp | FieldType::DateTime => {
+            if let Value::U32(inner) = v.value {
+                v.value = time_value(inner);
+            }
+        }
+        // the offset from UTC is filled in once the whole message has been processed
+        FieldType::LocalDateTime => {
+            if let Value::U32(inner) = v.value {
+                v.value = match inner.checked_add(PSEUDO_EPOCH) {
+                    Some(secs) => Value::LocalTime(LocalTime {
+                        secs,
+                        utc_offset: None,
+                    }),
+                    None => Value::Invalid,
+                };
+            }
+        }
+        FieldType::String | FieldType::LocaltimeIntoDay => {}
+        f => {
+            let key = match v.value {
+                Value::U8(k) => Some(usize::from(k)),
+                Value::U16(k) => Some(usize::from(k)),
+                _ => None,
+            };
+            if let Some(t) = key.and_then(|k| get_field_string_value_fn(f, k)) {
+                v.value = Value::Enum(t);
+                return;
+            }
+            let (scale, offset) = scale_and_offset(global_message_number, v.field_num);
+            if options.scale && (scale.is_some() || offset.is_some()) {
+                scale_field(v, scale.unwrap_or(1.0), offset.unwrap_or(0.0));
+            }
+        }
+    }
+}
+
+/// The scale and offset the profile gives a field.
+fn scale_and_offset(global_message_number: u16, field_num: usize) -> (Option<f64>, Option<f64>) {
+    field_info(global_message_number, field_num).map_or((None, None), |i| (i.scale, i.offset))
+}
@@ -1,6 +1,8 @@
 use std::collections::HashMap;
+use std::convert::TryFrom;
 use std::io::Read;
 
+use crate::error::FitError;
 use crate::types::data_field::DataField;
 use crate::Value;
 
@@ -11,17 +13,17 @@ pub struct DeveloperFieldDefinition {
     pub developer_data_index: u8,
 }
 impl DeveloperFieldDefinition {
-    pub fn new<R>(map: &mut R) -> Self
+    pub fn new<R>(map: &mut R) -> Result<Self, FitError>
     where
         R: Read,
     {
         let mut buf: [u8; 3] = [0; 3];
-        let _ = map.read(&mut buf);
-        Self {
-            field_number: buf[0].into(),
+        map.read_exact(&mut buf)?;
+        Ok(Self {
+            field_number: buf[0],
             size: buf[1],
             developer_data_index: buf[2],
-        }
+        })
     }
 }
 
@@ -31,31 +33,152 @@ pub struct DeveloperFieldDescription {
     pub field_definition_number: u8,
     pub fit_base_type: u8,
     pub field_name: String,
-    pub units: String,
+    pub array: Option<u8>,
+    pub components: Option<String>,
+    pub scale: Option<u8>,
+    pub offset: Option<i8>,
+    pub units: Option<String>,
+    pub native_mesg_num: Option<u16>,
+    pub native_field_num: Option<u8>,
 }
 impl DeveloperFieldDescription {
-    pub fn new(values: Vec<DataField>) -> Self {
+    pub fn new(values: Vec<DataField>) -> Result<Self, FitError> {
         let mut hmap: HashMap<usize, Value> = HashMap::with_capacity(6);
         values.into_iter().for_each(|v| {
             hmap.insert(v.field_num, v.value);
         });
-        Self {
-            developer_data_index: hmap.remove(&0).unwrap().into(),
-            field_definition_number: hmap.remove(&1).unwrap().into(),
-            fit_base_type: match hmap.remove(&2).unwrap() {
-                Value::U8(s) => s,
-                _ => panic!("can't call this on a non-u8 variant"),
+        Ok(Self {
+            developer_data_index: take_u8(&mut hmap, 0, "developer_data_index")?,
+            field_definition_number: take_u8(&mut hmap, 1, "field_definition_number")?,
+            fit_base_type: take_u8(&mut hmap, 2, "fit_base_type")?,
+            field_name: take_string(&mut hmap, 3, "field_name")?,
+            array: match hmap.remove(&4) {
+                Some(Value::U8(v)) => Some(v),
+                _ => None,
+            },
+            components: take_optional_string(&mut hmap, 5),
+            // a scale of zero would make every value infinite, so it's treated as missing
+            scale: match hmap.remove(&6) {
+                Some(Value::U8(v)) if v != 0 => Some(v),
+                _ => None,
             },
-            field_name: match hmap.remove(&3).unwrap() {
-                Value::String(v) => v.to_string(),
-                Value::Enum(s) => s.to_owned(),
-                _ => panic!("can't call this on a non-string/enum variant"),
+            offset: match hmap.remove(&7) {
+                Some(Value::I8(v)) => Some(v),
+                _ => None,
             },
-            units: match hmap.remove(&8).unwrap() {
-                Value::String(v) => v.to_string(),
-                Value::Enum(s) => s.to_owned(),
-                _ => panic!("can't call this on a non-string/enum variant"),
+            units: take_optional_string(&mut hmap, 8),
+            native_mesg_num: match hmap.remove(&14) {
+                Some(Value::U16(v)) => Some(v),
+                _ => None,
             },
+            native_field_num: match hmap.remove(&15) {
+                Some(Value::U8(v)) => Some(v),
+                _ => None,
+            },
+        })
+    }
+    /// Applies this field's scale and offset, if it has either, to a decoded value.
+    pub fn apply_scale(&self, v: Value) -> Value {
+        if self.scale.is_none() && self.offset.is_none() {
+            return v;
         }
+        let scale = f64::from(self.scale.unwrap_or(1));
+        let offset = f64::from(self.offset.unwrap_or(0));
+        v.scale_offset(scale, offset).unwrap_or(v)
+    }
+    /// The native field this developer field stands in for in messages with the given global
+    /// message number, if any.
+    pub fn native_field(&self, global_message_number: u16) -> Option<usize> {
+        match self.native_mesg_num {
+            Some(n) if n != global_message_number => None,
+            _ => self.native_field_num.map(usize::from),
+        }
+    }
+}
+
+/// Identifies the application or device that defined the developer fields with a given
+/// developer data index.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeveloperDataId {
+    pub developer_data_index: u8,
+    pub developer_id: Option<Vec<u8>>,
+    pub application_id: Option<Vec<u8>>,
+    pub manufacturer_id: Option<u16>,
+    pub application_version: Option<u32>,
+}
+impl DeveloperDataId {
+    pub fn new(values: &[DataField]) -> Result<Self, FitError> {
+        let mut hmap: HashMap<usize, Value> = values
+            .iter()
+            .map(|v| (v.field_num, v.value.clone()))
+            .collect();
+        Ok(Self {
+            developer_data_index: take_u8(&mut hmap, 3, "developer_data_index")?,
+            developer_id: take_bytes(&mut hmap, 0),
+            application_id: take_bytes(&mut hmap, 1),
+            manufacturer_id: match hmap.remove(&2) {
+                Some(Value::U16(v)) => Some(v),
+                _ => None,
+            },
+            application_version: match hmap.remove(&4) {
+                Some(Value::U32(v)) => Some(v),
+                _ => None,
+            },
+        })
+    }
+    /// The application ID formatted as a UUID, which is how Connect IQ apps are identified.
+    pub fn application_uuid(&self) -> Option<String> {
+        let id = self.application_id.as_ref().filter(|id| id.len() == 16)?;
+        let hex: String = id.iter().map(|b| format!("{:02x}", b)).collect();
+        Some(format!(
+            "{}-{}-{}-{}-{}",
+            &hex[..8],
+            &hex[8..12],
+            &hex[12..16],
+            &hex[16..20],
+            &hex[20..]
+        ))
+    }
+}
+
+fn take_u8(hmap: &mut HashMap<usize, Value>, k: usize, name: &str) -> Result<u8, FitError> {
+    match hmap.remove(&k) {
+        Some(v) => u8::try_from(&v)
+            .map_err(|_| FitError::BadFieldDescription(format!("{} is not a u8: {:?}", name, v))),
+        None => Err(FitError::BadFieldDescription(format!(
+            "{} is missing",
+            name
+        ))),
+    }
+}
+
+fn take_optional_string(hmap: &mut HashMap<usize, Value>, k: usize) -> Option<String> {
+    match hmap.remove(&k) {
+        Some(Value::String(v)) => Some(v),
+        Some(Value::Enum(s)) => Some(s.to_owned()),
+        _ => None,
+    }
+}
+
+fn take_bytes(hmap: &mut HashMap<usize, Value>, k: usize) -> Option<Vec<u8>> {
+    match hmap.remove(&k) {
+        Some(Value::Bytes(v)) | Some(Value::ArrU8(v)) => Some(v),
+        Some(Value::U8(v)) => Some(vec![v]),
+        _ => None,
+    }
+}
+
+fn take_string(hmap: &mut HashMap<usize, Value>, k: usize, name: &str) -> Result<String, FitError> {
+    match hmap.remove(&k) {
+        Some(Value::String(v)) => Ok(v),
+        Some(Value::Enum(s)) => Ok(s.to_owned()),
+        Some(v) => Err(FitError::BadFieldDescription(format!(
+            "{} is not a string: {:?}",
+            name, v
+        ))),
+        None => Err(FitError::BadFieldDescription(format!(
+            "{} is missing",
+            name
+        ))),
     }
 }
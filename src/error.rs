@@ -0,0 +1,88 @@
+use std::{error::Error, fmt, io};
+
+use fitsdk::MessageType;
+
+use crate::Value;
+
+//////////
+//// FitError
+//////////
+
+#[derive(Debug)]
+pub enum FitError {
+    Io(io::Error),
+    BadHeader(String),
+    BadDefinition(String),
+    BadFieldDescription(String),
+    TruncatedRecord,
+    CrcMismatch {
+        expected: u16,
+        calculated: u16,
+    },
+    UnknownBaseType(u8),
+    UndefinedLocalMessage(u8),
+    UnexpectedMessage {
+        expected: MessageType,
+        found: MessageType,
+    },
+    UnexpectedValue {
+        value: Value,
+        expected: &'static str,
+    },
+}
+impl FitError {
+    /// Whether the decoder is still aligned on a record boundary after this error, so that
+    /// decoding can carry on with the next record.
+    pub fn is_recoverable(&self) -> bool {
+        matches!(
+            self,
+            FitError::BadFieldDescription(_) | FitError::UnknownBaseType(_)
+        )
+    }
+}
+impl fmt::Display for FitError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FitError::Io(e) => write!(f, "i/o error: {}", e),
+            FitError::BadHeader(s) => write!(f, "bad file header: {}", s),
+            FitError::BadDefinition(s) => write!(f, "bad definition record: {}", s),
+            FitError::BadFieldDescription(s) => write!(f, "bad field description: {}", s),
+            FitError::TruncatedRecord => write!(f, "record is truncated"),
+            FitError::CrcMismatch {
+                expected,
+                calculated,
+            } => write!(
+                f,
+                "crc mismatch: expected {:#06x}, calculated {:#06x}",
+                expected, calculated
+            ),
+            FitError::UnknownBaseType(t) => write!(f, "unknown base type: {}", t),
+            FitError::UndefinedLocalMessage(n) => {
+                write!(f, "no definition for local message number {}", n)
+            }
+            FitError::UnexpectedMessage { expected, found } => {
+                write!(f, "expected a {:?} message, found {:?}", expected, found)
+            }
+            FitError::UnexpectedValue { value, expected } => {
+                write!(f, "can't convert {:?} to {}", value, expected)
+            }
+        }
+    }
+}
+impl Error for FitError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            FitError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+impl From<io::Error> for FitError {
+    fn from(e: io::Error) -> Self {
+        if e.kind() == io::ErrorKind::UnexpectedEof {
+            FitError::TruncatedRecord
+        } else {
+            FitError::Io(e)
+        }
+    }
+}
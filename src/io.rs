@@ -1,124 +1,183 @@
-use std::io::{Read, Seek, SeekFrom};
+use std::io::{self, Read};
 
-#[derive(Copy, Clone,Debug, PartialEq)]
+use crate::crc::crc_byte;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub enum Endianness {
     Little,
     Big,
 }
 
-pub fn read_u8<R>(map: &mut R) -> u8
+pub fn read_u8<R>(map: &mut R) -> io::Result<u8>
 where
     R: Read,
 {
     let mut buf: [u8; 1] = [0];
-    let _ = map.read(&mut buf);
-    buf[0]
+    map.read_exact(&mut buf)?;
+    Ok(buf[0])
 }
-pub fn read_i8<R>(map: &mut R) -> i8
+pub fn read_i8<R>(map: &mut R) -> io::Result<i8>
 where
     R: Read,
 {
-    read_u8(map) as i8
+    Ok(read_u8(map)? as i8)
 }
-pub fn read_u16<R>(map: &mut R, endianness: Endianness) -> u16
+pub fn read_u16<R>(map: &mut R, endianness: Endianness) -> io::Result<u16>
 where
     R: Read,
 {
-    let arr = arr2(map);
+    let arr = arr2(map)?;
     if endianness == Endianness::Little {
-        u16::from_le_bytes(arr)
+        Ok(u16::from_le_bytes(arr))
     } else {
-        u16::from_be_bytes(arr)
+        Ok(u16::from_be_bytes(arr))
     }
 }
-pub fn read_i16<R>(map: &mut R, endianness: Endianness) -> i16
+pub fn read_i16<R>(map: &mut R, endianness: Endianness) -> io::Result<i16>
 where
     R: Read,
 {
-    let arr = arr2(map);
+    let arr = arr2(map)?;
     if endianness == Endianness::Little {
-        i16::from_le_bytes(arr)
+        Ok(i16::from_le_bytes(arr))
     } else {
-        i16::from_be_bytes(arr)
+        Ok(i16::from_be_bytes(arr))
     }
 }
-pub fn read_u32<R>(map: &mut R, endianness: Endianness) -> u32
+pub fn read_u32<R>(map: &mut R, endianness: Endianness) -> io::Result<u32>
 where
     R: Read,
 {
-    let arr = arr4(map);
+    let arr = arr4(map)?;
     if endianness == Endianness::Little {
-        u32::from_le_bytes(arr)
+        Ok(u32::from_le_bytes(arr))
     } else {
-        u32::from_be_bytes(arr)
+        Ok(u32::from_be_bytes(arr))
     }
 }
-pub fn read_i32<R>(map: &mut R, endianness: Endianness) -> i32
+pub fn read_i32<R>(map: &mut R, endianness: Endianness) -> io::Result<i32>
 where
     R: Read,
 {
-    let arr = arr4(map);
+    let arr = arr4(map)?;
     if endianness == Endianness::Little {
-        i32::from_le_bytes(arr)
+        Ok(i32::from_le_bytes(arr))
     } else {
-        i32::from_be_bytes(arr)
+        Ok(i32::from_be_bytes(arr))
     }
 }
-pub fn read_u64<R>(map: &mut R, endianness: Endianness) -> u64
+pub fn read_u64<R>(map: &mut R, endianness: Endianness) -> io::Result<u64>
 where
     R: Read,
 {
-    let arr = arr8(map);
+    let arr = arr8(map)?;
     if endianness == Endianness::Little {
-        u64::from_le_bytes(arr)
+        Ok(u64::from_le_bytes(arr))
     } else {
-        u64::from_be_bytes(arr)
+        Ok(u64::from_be_bytes(arr))
     }
 }
-pub fn read_i64<R>(map: &mut R, endianness: Endianness) -> i64
+pub fn read_i64<R>(map: &mut R, endianness: Endianness) -> io::Result<i64>
 where
     R: Read,
 {
-    let arr = arr8(map);
+    let arr = arr8(map)?;
     if endianness == Endianness::Little {
-        i64::from_le_bytes(arr)
+        Ok(i64::from_le_bytes(arr))
     } else {
-        i64::from_be_bytes(arr)
+        Ok(i64::from_be_bytes(arr))
     }
 }
 
-fn arr2<R>(map: &mut R) -> [u8; 2]
+fn arr2<R>(map: &mut R) -> io::Result<[u8; 2]>
 where
     R: Read,
 {
     let mut buf: [u8; 2] = [0; 2];
-    let _ = map.read(&mut buf);
-    buf
+    map.read_exact(&mut buf)?;
+    Ok(buf)
 }
 
-pub fn arr4<R>(map: &mut R) -> [u8; 4]
+pub fn arr4<R>(map: &mut R) -> io::Result<[u8; 4]>
 where
     R: Read,
 {
     let mut buf: [u8; 4] = [0; 4];
-    let _ = map.read(&mut buf);
-    buf
+    map.read_exact(&mut buf)?;
+    Ok(buf)
 }
 
-fn arr8<R>(map: &mut R) -> [u8; 8]
+fn arr8<R>(map: &mut R) -> io::Result<[u8; 8]>
 where
     R: Read,
 {
     let mut buf: [u8; 8] = [0; 8];
-    let _ = map.read(&mut buf);
-    buf
+    map.read_exact(&mut buf)?;
+    Ok(buf)
+}
+
+pub fn read_bytes<R>(map: &mut R, s: u8) -> io::Result<Vec<u8>>
+where
+    R: Read,
+{
+    let mut buf = vec![0; s.into()];
+    map.read_exact(&mut buf)?;
+    Ok(buf)
 }
 
-pub fn skip_bytes<R>(map: &mut R, s: u8)
+pub fn skip_bytes<R>(map: &mut R, s: u8) -> io::Result<()>
 where
-    R: Seek,
+    R: Read,
 {
-    map.seek(SeekFrom::Current(s.into())).unwrap();
+    let skipped = io::copy(&mut (&mut *map).take(s.into()), &mut io::sink())?;
+    if skipped < u64::from(s) {
+        return Err(io::ErrorKind::UnexpectedEof.into());
+    }
+    Ok(())
+}
+
+//////////
+//// CountingReader
+//////////
+
+/// Wraps a reader and keeps track of how many bytes have been read from it, so that sources
+/// which can't seek still know where they are in the file, along with the CRC of those bytes.
+pub struct CountingReader<R> {
+    inner: R,
+    position: u64,
+    crc: u16,
+}
+impl<R> CountingReader<R> {
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            position: 0,
+            crc: 0,
+        }
+    }
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+    pub fn crc(&self) -> u16 {
+        self.crc
+    }
+    pub fn reset_crc(&mut self) {
+        self.crc = 0;
+    }
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+}
+impl<R> Read for CountingReader<R>
+where
+    R: Read,
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.position += n as u64;
+        self.crc = buf[..n].iter().fold(self.crc, |crc, b| crc_byte(crc, *b));
+        Ok(n)
+    }
 }
 
 #[cfg(test)]
@@ -129,98 +188,133 @@ mod tests {
     #[test]
     fn it_reads_u8() {
         let a = [42];
-        assert_eq!(read_u8(&mut a.as_ref()), 42);
+        assert_eq!(read_u8(&mut a.as_ref()).unwrap(), 42);
     }
     #[test]
     fn it_reads_u16_le() {
         let endianness = Endianness::Little;
         let a = [42, 151];
-        assert_eq!(read_u16(&mut a.as_ref(), endianness), 38698);
+        assert_eq!(read_u16(&mut a.as_ref(), endianness).unwrap(), 38698);
     }
     #[test]
     fn it_reads_u16_be() {
         let endianness = Endianness::Big;
         let a = [42, 151];
-        assert_eq!(read_u16(&mut a.as_ref(), endianness), 10903);
+        assert_eq!(read_u16(&mut a.as_ref(), endianness).unwrap(), 10903);
     }
     #[test]
     fn it_reads_u32_le() {
         let endianness = Endianness::Little;
         let a = [42, 151, 138, 217];
-        assert_eq!(read_u32(&mut a.as_ref(), endianness), 3649738538);
+        assert_eq!(read_u32(&mut a.as_ref(), endianness).unwrap(), 3649738538);
     }
     #[test]
     fn it_reads_u32_be() {
         let endianness = Endianness::Big;
         let a = [42, 151, 138, 217];
-        assert_eq!(read_u32(&mut a.as_ref(), endianness), 714574553);
+        assert_eq!(read_u32(&mut a.as_ref(), endianness).unwrap(), 714574553);
     }
     #[test]
     fn it_reads_u64_le() {
         let endianness = Endianness::Little;
         let a = [42, 151, 138, 217, 59, 205, 235, 102];
-        assert_eq!(read_u64(&mut a.as_ref(), endianness), 7416246868332156714);
+        assert_eq!(
+            read_u64(&mut a.as_ref(), endianness).unwrap(),
+            7416246868332156714
+        );
     }
     #[test]
     fn it_reads_u64_be() {
         let endianness = Endianness::Big;
         let a = [42, 151, 138, 217, 59, 205, 235, 102];
-        assert_eq!(read_u64(&mut a.as_ref(), endianness), 3069074336692169574);
+        assert_eq!(
+            read_u64(&mut a.as_ref(), endianness).unwrap(),
+            3069074336692169574
+        );
     }
     #[test]
     fn it_reads_i8() {
         let a = [234];
-        assert_eq!(read_i8(&mut a.as_ref()), -22);
+        assert_eq!(read_i8(&mut a.as_ref()).unwrap(), -22);
     }
     #[test]
     fn it_reads_i16_le() {
         let endianness = Endianness::Little;
         let a = [234, 151];
-        assert_eq!(read_i16(&mut a.as_ref(), endianness), -26646);
+        assert_eq!(read_i16(&mut a.as_ref(), endianness).unwrap(), -26646);
     }
     #[test]
     fn it_reads_i16_be() {
         let endianness = Endianness::Big;
         let a = [234, 151];
-        assert_eq!(read_i16(&mut a.as_ref(), endianness), -5481);
+        assert_eq!(read_i16(&mut a.as_ref(), endianness).unwrap(), -5481);
     }
     #[test]
     fn it_reads_i32_le() {
         let endianness = Endianness::Little;
         let a = [234, 151, 138, 217];
-        assert_eq!(read_i32(&mut a.as_ref(), endianness), -645228566);
+        assert_eq!(read_i32(&mut a.as_ref(), endianness).unwrap(), -645228566);
     }
     #[test]
     fn it_reads_i32_be() {
         let endianness = Endianness::Big;
         let a = [234, 151, 138, 217];
-        assert_eq!(read_i32(&mut a.as_ref(), endianness), -359167271);
+        assert_eq!(read_i32(&mut a.as_ref(), endianness).unwrap(), -359167271);
     }
     #[test]
     fn it_reads_i64_le() {
         let endianness = Endianness::Little;
         let a = [234, 151, 138, 217, 59, 205, 235, 102];
-        assert_eq!(read_i64(&mut a.as_ref(), endianness), 7416246868332156906);
+        assert_eq!(
+            read_i64(&mut a.as_ref(), endianness).unwrap(),
+            7416246868332156906
+        );
     }
     #[test]
     fn it_reads_i64_be() {
         let endianness = Endianness::Big;
         let a = [234, 151, 138, 217, 59, 205, 235, 102];
-        assert_eq!(read_i64(&mut a.as_ref(), endianness), -1542611681735218330);
+        assert_eq!(
+            read_i64(&mut a.as_ref(), endianness).unwrap(),
+            -1542611681735218330
+        );
     }
     #[test]
     fn it_consumes_bytes() {
         let endianness = Endianness::Little;
         let a = [234, 151, 138, 217, 59];
         let mut c = Cursor::new(a);
-        let _ = read_u32(&mut c, endianness);
-        assert_eq!(read_u8(&mut c), 59);
+        let _ = read_u32(&mut c, endianness).unwrap();
+        assert_eq!(read_u8(&mut c).unwrap(), 59);
     }
     #[test]
     fn it_skips_bytes() {
         let a = [234, 151, 138, 217, 59, 205, 235, 102];
         let mut c = Cursor::new(a);
-        skip_bytes(&mut c, 5);
-        assert_eq!(read_u8(&mut c), 205);
+        skip_bytes(&mut c, 5).unwrap();
+        assert_eq!(read_u8(&mut c).unwrap(), 205);
+    }
+    #[test]
+    fn it_fails_to_skip_past_the_end() {
+        let a = [234, 151, 138];
+        let err = skip_bytes(&mut a.as_ref(), 5).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+    }
+    #[test]
+    fn it_counts_bytes_read() {
+        let endianness = Endianness::Little;
+        let a = [234, 151, 138, 217, 59, 205, 235, 102];
+        let mut c = CountingReader::new(a.as_ref());
+        let _ = read_u16(&mut c, endianness).unwrap();
+        skip_bytes(&mut c, 3).unwrap();
+        assert_eq!(c.position(), 5);
+        assert_eq!(c.crc(), crate::crc::crc16(&a[..5]));
+    }
+    #[test]
+    fn it_fails_on_short_reads() {
+        let endianness = Endianness::Little;
+        let a = [234, 151, 138];
+        let err = read_u32(&mut a.as_ref(), endianness).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
     }
 }
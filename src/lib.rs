@@ -1,520 +1,309 @@
 mod consts;
+mod crc;
+mod decoder;
 mod developer_fields;
+mod error;
 mod io;
+pub mod messages;
+mod options;
+mod profile;
+mod segments;
+mod stream;
 mod types;
 mod value;
 
-use consts::*;
-use copyless::VecHelper;
-use developer_fields::DeveloperFieldDescription;
-use fitsdk::{
-    get_field_offset_fn, get_field_scale_fn, get_field_string_value_fn, get_field_type_fn,
-    get_message_timestamp_field, get_message_type, FieldType, MessageType,
-};
+pub use crc::verify;
+use decoder::Decoder;
+pub use developer_fields::DeveloperDataId;
+pub use developer_fields::DeveloperFieldDefinition;
+pub use error::FitError;
+pub use io::Endianness;
 use io::*;
 use memmap::{Mmap, MmapOptions};
-use std::collections::VecDeque;
-use std::io::{Cursor, Read, Seek, SeekFrom};
-use std::{fs::File, path::PathBuf};
-use types::data_field::DataField;
-use types::definition_record::DefinitionRecord;
-use types::dev_data_field::DevDataField;
-use types::file_header::FileHeader;
-use types::header_byte::HeaderByte;
-use types::message::Message;
-pub use value::Value;
+pub use options::DecodeOptions;
+use segments::Segments;
+use std::io::{Cursor, Read};
+use std::{
+    fs::File,
+    path::{Path, PathBuf},
+};
+pub use stream::{Messages, StreamDecoder};
+pub use types::data_field::DataField;
+pub use types::definition_record::DefinitionRecord;
+pub use types::dev_data_field::DevDataField;
+pub use types::field_definition::FieldDefinition;
+pub use types::file_header::FileHeader;
+pub use types::header_byte::HeaderByte;
+pub use types::message::Message;
+pub use types::record::Record;
+pub use types::timestamp::{LocalTime, Timestamp};
+pub use value::{ArrayElement, Value};
 
 //////////
 //// Fit
 //////////
 
-pub struct Fit {
-    file_header: FileHeader,
+/// Decodes the messages in a FIT file read from `R`. Files opened with `Fit::open` are
+/// memory-mapped, but any in-memory buffer or `Read` source works just as well.
+pub struct Fit<R = Cursor<Mmap>> {
+    segments: Segments,
     data_len: u64,
-    buf: Cursor<Mmap>,
-    queue: VecDeque<(u8, DefinitionRecord)>,
-    developer_fields: Vec<DeveloperFieldDescription>,
-    last_timestamp: u32,
+    buf: CountingReader<R>,
+    decoder: Decoder,
+    /// All of the data, when it's already in memory, so strict mode can verify it up front.
+    data: Option<fn(&R) -> &[u8]>,
+    verified: bool,
 }
 impl Fit {
+    /// Opens and memory-maps the file at `path`, panicking if it can't be read.
+    /// Prefer `Fit::open` when the input isn't trusted.
     pub fn new(path: &PathBuf) -> Self {
-        let file = File::open(path).unwrap();
-        let mmap = unsafe { MmapOptions::new().map(&file).unwrap() };
-        let mut buf = Cursor::new(mmap);
-
-        let fh = FileHeader::new(&mut buf);
-        Self {
-            data_len: u64::from(&fh.num_record_bytes + 14),
-            file_header: fh,
-            buf,
-            queue: VecDeque::new(),
-            developer_fields: Vec::new(),
-            last_timestamp: 0,
-        }
+        Self::open(path).expect("failed to open FIT file")
     }
-    pub fn file_header(&self) -> &FileHeader {
-        &self.file_header
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, FitError> {
+        let file = File::open(path)?;
+        let mmap = unsafe { MmapOptions::new().map(&file)? };
+        let mut fit = Self::from_reader(Cursor::new(mmap))?;
+        fit.data = Some(|c| &c.get_ref()[..]);
+        Ok(fit)
     }
 }
-impl Iterator for Fit {
-    type Item = Message;
-    fn next(&mut self) -> Option<Self::Item> {
-        loop {
-            let r = self.buf.seek(SeekFrom::Current(0)).unwrap();
-            if r > self.data_len {
-                return None;
-            }
-            let h = HeaderByte::new(&mut self.buf);
-            if h.definition {
-                let d = DefinitionRecord::new(&mut self.buf, h.dev_fields);
-                self.queue.push_front((h.local_num, d));
-            } else {
-                // if no definition is found, skip this loop
-                let definition = match self.queue.iter().find(|x| x.0 == h.local_num) {
-                    None => continue,
-                    Some((_, def)) => def,
-                };
-                let message_type = get_message_type(definition.global_message_number);
-                let mut dev_fields: Option<Vec<DevDataField>> = None;
-                let mut values = Vec::with_capacity(definition.field_definitions.len());
-
-                // read all the values for this reacord type's defined fields
-                for fd in definition.field_definitions.iter() {
-                    if message_type == MessageType::None {
-                        skip_bytes(&mut self.buf, fd.size);
-                    } else if let Some(data) =
-                        read_next_field(fd.size, fd.base_type, definition.endianness, &mut self.buf)
-                    {
-                        values
-                            .alloc()
-                            .init(DataField::new(fd.definition_number, data));
-                    }
-                }
-
-                // if this is a developer field definition
-                if message_type == MessageType::FieldDescription {
-                    let d = DeveloperFieldDescription::new(values);
-                    self.developer_fields.push(d);
-                    continue;
-                }
-
-                // this is not a valid message, so there's no more processing to do this loop
-                if message_type == MessageType::None || values.is_empty() {
-                    continue;
-                }
-
-                // if this record is a message that contains developer-defined fields read those too
-                if let Some(dev_field_defs) = &definition.developer_fields {
-                    let mut temp_dev_fields = Vec::new();
-                    for df in dev_field_defs.iter() {
-                        for e in &self.developer_fields {
-                            if e.developer_data_index == 1 {
-                                if let Some(v) = read_next_field(
-                                    e.fit_base_type,
-                                    df.size,
-                                    definition.endianness,
-                                    &mut self.buf,
-                                ) {
-                                    temp_dev_fields.push(DevDataField::new(
-                                        df.developer_data_index,
-                                        df.field_number,
-                                        v,
-                                    ));
-                                }
-                            }
-                        }
-                    }
-                    dev_fields = Some(temp_dev_fields);
-                }
-
-                // check each value in case the raw value needs further processing
-                let scales = get_field_scale_fn(message_type);
-                let offsets = get_field_offset_fn(message_type);
-                let fields = get_field_type_fn(message_type);
-                for v in &mut values {
-                    process_value(v, fields, scales, offsets);
-                }
-
-                // some FIT files use a compressed timestamp to save a little more space
-                if let Some(time_offset) = h.compressed_timestamp() {
-                    let mut timestamp = self.last_timestamp
-                        & COMPRESSED_HEADER_LAST_TIMESTAMP_MASK + u32::from(time_offset);
-                    if time_offset
-                        < (self.last_timestamp as u8 & COMPRESSED_HEADER_TIME_OFFSET_MASK)
-                    {
-                        timestamp += COMPRESSED_HEADER_TIME_OFFSET_ROLLOVER
-                    };
-
-                    if let Some(timestamp_field_number) = get_message_timestamp_field(message_type)
-                    {
-                        values.alloc().init(DataField::new(
-                            timestamp_field_number,
-                            Value::Time(timestamp + PSEUDO_EPOCH),
-                        ));
-                    }
-                }
-
-                // if any values were invalid we have a vec that's now too long
-                values.shrink_to_fit();
-                return Some(Message {
-                    values,
-                    kind: message_type,
-                    dev_values: dev_fields,
-                });
-            }
-        }
+impl<'a> Fit<Cursor<&'a [u8]>> {
+    pub fn from_bytes(data: &'a [u8]) -> Result<Self, FitError> {
+        let mut fit = Self::from_reader(Cursor::new(data))?;
+        fit.data = Some(|c| &c.get_ref()[..]);
+        Ok(fit)
     }
 }
-
-#[allow(clippy::cognitive_complexity)]
-fn read_next_field<R>(size: u8, base_type: u8, endianness: Endianness, map: &mut R) -> Option<Value>
+impl<R> Fit<R>
 where
-    R: Read + Seek,
+    R: Read,
 {
-    match base_type {
-        0 | 13 => {
-            // enum / byte
-            if size > 1 {
-                println!("0/13:enum/byte: {}", size);
-                skip_bytes(map, size);
-                None
-            } else {
-                match read_u8(map) {
-                    0xFF => None,
-                    v => Some(Value::U8(v)),
-                }
-            }
-        }
-        1 => {
-            // sint8
-            if size > 1 {
-                println!("1 i8: {}", size);
-                skip_bytes(map, size);
-                None
-            } else {
-                match read_i8(map) {
-                    0x7F => None,
-                    v => Some(Value::I8(v)),
-                }
-            }
-        }
-        2 => {
-            // uint8
-            if size > 1 {
-                let mut buf: Vec<_> = Vec::with_capacity(size.into());
-                let _ = map.take(size.into()).read_to_end(&mut buf);
-                buf.retain(|x| *x != 0xFF);
-                if buf.is_empty() {
-                    None
-                } else {
-                    Some(Value::ArrU8(buf))
-                }
-            } else {
-                match read_u8(map) {
-                    0xFF => None,
-                    v => Some(Value::U8(v)),
-                }
-            }
-        }
-        3 => {
-            // sint16
-            let number_of_values = size / 2;
-            if number_of_values > 1 {
-                println!("3 i16: {}", size);
-                skip_bytes(map, size);
-                None
-            } else {
-                let val = read_i16(map, endianness);
-                if val == 0x7FFF {
-                    None
-                } else {
-                    Some(Value::I16(val))
-                }
-            }
-        }
-        4 => {
-            // uint16
-            let number_of_values = size / 2;
-            if number_of_values > 1 {
-                let c: Vec<_> = (0..number_of_values)
-                    .filter_map(|_| match read_u16(map, endianness) {
-                        0xFFFF => None,
-                        v => Some(v),
-                    })
-                    .collect();
-                if c.is_empty() {
-                    None
-                } else {
-                    Some(Value::ArrU16(c))
-                }
-            } else {
-                let val = read_u16(map, endianness);
-                if val == 0xFFFF {
-                    None
-                } else {
-                    Some(Value::U16(val))
-                }
-            }
-        }
-        5 => {
-            // sint32
-            let number_of_values = size / 4;
-            if number_of_values > 1 {
-                println!("5 i32: {}", size);
-                skip_bytes(map, size);
-                None
-            } else {
-                let val = read_i32(map, endianness);
-                if val == 0x7F_FFF_FFF {
-                    None
-                } else {
-                    Some(Value::I32(val))
-                }
-            }
-        }
-        6 => {
-            // uint32
-            let number_of_values = size / 4;
-            if number_of_values > 1 {
-                let c: Vec<_> = (0..number_of_values)
-                    .filter_map(|_| match read_u32(map, endianness) {
-                        0xFFFF_FFFF => None,
-                        v => Some(v),
-                    })
-                    .collect();
-                if c.is_empty() {
-                    None
-                } else {
-                    Some(Value::ArrU32(c))
-                }
-            } else {
-                let val = read_u32(map, endianness);
-                if val == 0xFFFF_FFFF {
-                    None
-                } else {
-                    Some(Value::U32(val))
-                }
-            }
+    pub fn from_reader(reader: R) -> Result<Self, FitError> {
+        let mut buf = CountingReader::new(reader);
+        let mut segments = Segments::default();
+        let mut decoder = Decoder::default();
+
+        let data_len = segments
+            .start(FileHeader::new(&mut buf), &mut decoder)?
+            .map_or(0, FileHeader::data_end);
+        Ok(Self {
+            data_len,
+            segments,
+            buf,
+            decoder,
+            data: None,
+            verified: false,
+        })
+    }
+    /// Sets the options that control how values are decoded, replacing the defaults. This is
+    /// where strict mode and developer fields standing in for native ones are enabled too.
+    pub fn options(mut self, options: DecodeOptions) -> Self {
+        *self.decoder.options_mut() = options;
+        self
+    }
+    pub fn file_header(&self) -> &FileHeader {
+        &self.segments.file_headers()[0]
+    }
+    /// The headers of every FIT file found so far, when several are chained together in the
+    /// same data. A message's `segment` is its file's index in this list.
+    pub fn file_headers(&self) -> &[FileHeader] {
+        self.segments.file_headers()
+    }
+    /// Whether the CRC at the end of each file matches its contents, or `None` if the decoder
+    /// hasn't reached the end of the first file yet.
+    pub fn crc_valid(&self) -> Option<bool> {
+        self.segments.crc_valid()
+    }
+    /// The definition currently in use for each of the 16 local message numbers, which is
+    /// mostly useful when debugging a file that won't decode.
+    pub fn definitions(&self) -> &[Option<DefinitionRecord>] {
+        self.decoder.definitions()
+    }
+    /// The application or device that defined the developer fields with the given data index,
+    /// taken from the `developer_data_id` messages decoded so far.
+    pub fn developer_data_id(&self, developer_data_index: u8) -> Option<&DeveloperDataId> {
+        self.decoder.developer_data_id(developer_data_index)
+    }
+    /// How many seconds local time is ahead of UTC in this file, once a message that records
+    /// both, such as `activity`, has been decoded.
+    pub fn utc_offset(&self) -> Option<i32> {
+        self.decoder.utc_offset()
+    }
+    /// Returns an iterator that yields every message, or the error that stopped it being decoded.
+    /// Decoding continues after an error only if `FitError::is_recoverable` is true, and the
+    /// decoder isn't in strict mode.
+    pub fn results(&mut self) -> Results<'_, R> {
+        Results { fit: self }
+    }
+    /// Returns an iterator over the records in the file as they were written, including the
+    /// definition records, which is mostly useful when debugging the output of a device.
+    pub fn records(&mut self) -> Records<'_, R> {
+        Records { fit: self }
+    }
+
+    fn try_next(&mut self) -> Option<Result<Message, FitError>> {
+        if self.segments.is_stopped() {
+            return None;
         }
-        7 => {
-            // string
-            let mut buf: Vec<_> = Vec::with_capacity(size.into());
-            let _ = map.take(size.into()).read_to_end(&mut buf);
-            buf.retain(|b| *b != 0x00);
-            if let Ok(string) = String::from_utf8(buf) {
-                Some(Value::String(string))
-            } else {
+        let r = self.next_message();
+        self.stop_on_error(r)
+    }
+
+    fn stop_on_error<T>(&mut self, r: Result<Option<T>, FitError>) -> Option<Result<T, FitError>> {
+        match r {
+            Ok(Some(m)) => Some(Ok(m)),
+            Ok(None) => {
+                self.segments.stop();
                 None
             }
-        }
-        8 => {
-            // float32
-            let number_of_values = size / 4;
-            if number_of_values > 1 {
-                println!("8 f32: {}", size);
-                skip_bytes(map, size);
-                None
-            } else {
-                let uval = read_u32(map, endianness);
-                if uval == 0xFF_FFF_FFF {
-                    None
-                } else {
-                    let val = f32::from_bits(uval);
-                    Some(Value::F32(val))
-                }
+            Err(e) => {
+                let strict = self.decoder.options().strict;
+                self.segments.stop_on_error(&e, strict);
+                Some(Err(e))
             }
         }
-        9 => {
-            // float64
-            let number_of_values = size / 8;
-            if number_of_values > 1 {
-                println!("9 f64: {}", size);
-                skip_bytes(map, size);
-                None
-            } else {
-                let uval = read_u64(map, endianness);
-                if uval == 0xF_FFF_FFF_FFF_FFF_FFF {
-                    None
-                } else {
-                    let val = f64::from_bits(uval);
-                    Some(Value::F64(val))
-                }
+    }
+
+    fn next_message(&mut self) -> Result<Option<Message>, FitError> {
+        while let Some(record) = self.next_record(false)? {
+            if let Some(mut m) = self.decoder.decode_message(record)? {
+                m.segment = self.segments.current();
+                return Ok(Some(m));
             }
         }
-        10 => {
-            // uint8z
-            if size > 1 {
-                println!("10:uint8z {}", size);
-                skip_bytes(map, size);
-                None
-            } else {
-                let val = read_u8(map);
-                if val == 0x00 {
-                    None
-                } else {
-                    Some(Value::U8(val))
-                }
+        Ok(None)
+    }
+
+    fn next_record(&mut self, keep_raw_bytes: bool) -> Result<Option<Record>, FitError> {
+        // the first header was read before strict mode could be enabled, and data that's all in
+        // memory can be refused before any of it is decoded
+        if self.decoder.options().strict && !self.verified {
+            self.verified = true;
+            self.file_header().check_crc()?;
+            if let Some(data) = self.data {
+                crc::verify(data(self.buf.get_ref()))?;
             }
         }
-        11 => {
-            // uint16z
-            let number_of_values = size / 2;
-            if number_of_values > 1 {
-                println!("11 u16: {}", size);
-                skip_bytes(map, size);
-                None
-            } else {
-                let val = read_u16(map, endianness);
-                if val == 0x0000 {
-                    None
-                } else {
-                    Some(Value::U16(val))
-                }
+        loop {
+            if self.buf.position() < self.data_len {
+                let offset = self.buf.position();
+                return self
+                    .decoder
+                    .read_record(&mut self.buf, offset, keep_raw_bytes)
+                    .map(Some);
             }
-        }
-        12 => {
-            // uint32z
-            let number_of_values = size / 4;
-            if number_of_values > 1 {
-                println!("12 u32: {}", size);
-                skip_bytes(map, size);
-                None
-            } else {
-                let val = read_u32(map, endianness);
-                if val == 0x0000_0000 {
-                    None
-                } else {
-                    Some(Value::U32(val))
-                }
+            self.check_crc()?;
+            if !self.next_segment()? {
+                return Ok(None);
             }
         }
-        14 => {
-            // sint64
-            let number_of_values = size / 8;
-            if number_of_values > 1 {
-                println!("14 i64: {}", size);
-                skip_bytes(map, size);
-                None
-            } else {
-                let val = read_i64(map, endianness);
-                if val == 0x7_FFF_FFF_FFF_FFF_FFF {
-                    None
-                } else {
-                    Some(Value::I64(val))
-                }
-            }
+    }
+
+    fn next_segment(&mut self) -> Result<bool, FitError> {
+        // the data ending cleanly after a CRC means there are no more files chained on
+        let start = self.buf.position();
+        let mut first: [u8; 1] = [0];
+        if self.buf.read(&mut first)? == 0 {
+            return Ok(false);
         }
-        15 => {
-            // uint64
-            let number_of_values = size / 8;
-            if number_of_values > 1 {
-                println!("15 u64: {}", size);
-                skip_bytes(map, size);
-                None
-            } else {
-                let val = read_u64(map, endianness);
-                if val == 0xF_FFF_FFF_FFF_FFF_FFF {
-                    None
-                } else {
-                    Some(Value::U64(val))
-                }
+        let fh = FileHeader::new(&mut (&first[..]).chain(&mut self.buf));
+        match self.segments.start(fh, &mut self.decoder)? {
+            Some(fh) => {
+                self.data_len = start + fh.data_end();
+                Ok(true)
             }
+            None => Ok(false),
         }
-        16 => {
-            // uint64z
-            let number_of_values = size / 8;
-            if number_of_values > 1 {
-                println!("16 u64: {}", size);
-                skip_bytes(map, size);
-                None
-            } else {
-                let val = read_u64(map, endianness);
-                if val == 0x0_000_000_000_000_000 {
-                    None
-                } else {
-                    Some(Value::U64(val))
-                }
+    }
+
+    fn check_crc(&mut self) -> Result<(), FitError> {
+        let calculated = self.buf.crc();
+        let expected = read_u16(&mut self.buf, Endianness::Little)?;
+        self.buf.reset_crc();
+        let strict = self.decoder.options().strict;
+        self.segments.end(expected, calculated, strict)
+    }
+}
+impl<R> Iterator for Fit<R>
+where
+    R: Read,
+{
+    type Item = Message;
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.try_next()? {
+                Ok(m) => return Some(m),
+                // skip over anything that couldn't be decoded
+                Err(_) => continue,
             }
         }
-        _ => None,
     }
 }
 
-#[allow(unused_must_use)]
-fn process_value(
-    v: &mut DataField,
-    fields: fitsdk::MatchFieldTypeFn,
-    scales: fitsdk::MatchScaleFn,
-    offsets: fitsdk::MatchOffsetFn,
-) {
-    match fields(v.field_num) {
-        FieldType::None => (),
-        FieldType::Coordinates => {
-            if let Value::I32(ref inner) = v.value {
-                let coord = *inner as f32 * COORD_SEMICIRCLES_CALC;
-                std::mem::replace(&mut v.value, Value::F32(coord));
-            }
-        }
-        FieldType::Timestamp => {
-            if let Value::U32(ref inner) = v.value {
-                // self.last_timestamp = *inner;
-                let date = *inner + PSEUDO_EPOCH;
-                std::mem::replace(&mut v.value, Value::Time(date));
-            }
-        }
-        FieldType::DateTime => {
-            if let Value::U32(ref inner) = v.value {
-                let date = *inner + PSEUDO_EPOCH;
-                std::mem::replace(&mut v.value, Value::Time(date));
-            }
-        }
-        FieldType::LocalDateTime => {
-            if let Value::U32(ref inner) = v.value {
-                let time = *inner + PSEUDO_EPOCH - 3600;
-                std::mem::replace(&mut v.value, Value::Time(time));
-            }
-        }
-        FieldType::String | FieldType::LocaltimeIntoDay => {}
-        FieldType::Uint8
-        | FieldType::Uint8Z
-        | FieldType::Uint16
-        | FieldType::Uint16Z
-        | FieldType::Uint32
-        | FieldType::Uint32Z
-        | FieldType::Sint8 => {
-            if let Some(s) = scales(v.field_num) {
-                v.value.scale(s);
-            }
-            if let Some(o) = offsets(v.field_num) {
-                v.value.offset(o);
-            }
+//////////
+//// Results
+//////////
+
+pub struct Results<'a, R> {
+    fit: &'a mut Fit<R>,
+}
+impl<'a, R> Iterator for Results<'a, R>
+where
+    R: Read,
+{
+    type Item = Result<Message, FitError>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.fit.try_next()
+    }
+}
+
+//////////
+//// Records
+//////////
+
+pub struct Records<'a, R> {
+    fit: &'a mut Fit<R>,
+}
+impl<'a, R> Iterator for Records<'a, R>
+where
+    R: Read,
+{
+    type Item = Result<Record, FitError>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.fit.segments.is_stopped() {
+            return None;
         }
-        f => {
-            if let Value::U8(k) = v.value {
-                if let Some(t) = get_field_string_value_fn(f, usize::from(k)) {
-                    std::mem::replace(&mut v.value, Value::Enum(t));
-                }
-            } else if let Value::U16(k) = v.value {
-                if let Some(t) = get_field_string_value_fn(f, usize::from(k)) {
-                    std::mem::replace(&mut v.value, Value::Enum(t));
-                }
-            }
+        let r = self.fit.next_record(true);
+        if let Ok(Some(record)) = &r {
+            // later records can depend on earlier ones, e.g. for developer field descriptions,
+            // and a message that can't be decoded doesn't stop its record being returned
+            let _ = self.fit.decoder.decode_message(record.clone());
         }
+        self.fit.stop_on_error(r)
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::consts::PSEUDO_EPOCH;
+    use crate::decoder::{
+        expand_components, expand_timestamp, expand_timestamp_16, process_value, read_next_field,
+        Decoder,
+    };
+    use crate::types::data_field::DataField;
     use crate::types::field_definition::FieldDefinition;
+    use crate::types::header_byte::HeaderByte;
 
     use super::*;
+    use std::collections::HashMap;
     use std::io::Cursor;
 
     #[test]
     fn it_reads_file_header() {
         let a = [14, 16, 116, 6, 51, 92, 1, 0, 46, 70, 73, 84, 213, 14];
         let mut c = Cursor::new(a);
-        let fh = FileHeader::new(&mut c);
+        let fh = FileHeader::new(&mut c).unwrap();
         assert_eq!(
             fh,
             FileHeader {
@@ -523,16 +312,56 @@ mod tests {
                 profile_version: 1652,
                 num_record_bytes: 89139,
                 fileext: true,
-                crc: 3797,
+                crc: Some(3797),
+                calculated_crc: 3797,
             }
         );
+        assert!(fh.has_crc());
+        assert_eq!(fh.crc_valid(), Some(true));
+        assert_eq!(fh.data_end(), 89153);
+    }
+
+    #[test]
+    fn it_reads_legacy_file_header() {
+        let a = [12, 16, 116, 6, 51, 92, 1, 0, 46, 70, 73, 84, 64];
+        let mut c = Cursor::new(a);
+        let fh = FileHeader::new(&mut c).unwrap();
+        assert!(!fh.has_crc());
+        assert_eq!(fh.crc_valid(), None);
+        assert_eq!(fh.data_end(), 89151);
+        assert_eq!(c.position(), 12);
+    }
+
+    #[test]
+    fn it_skips_extra_file_header_bytes() {
+        let a = [
+            16, 16, 116, 6, 51, 92, 1, 0, 46, 70, 73, 84, 213, 14, 1, 2, 64,
+        ];
+        let mut c = Cursor::new(a);
+        let fh = FileHeader::new(&mut c).unwrap();
+        assert_eq!(fh.crc_valid(), Some(true));
+        assert_eq!(fh.data_end(), 89155);
+        assert_eq!(c.position(), 16);
+    }
+
+    #[test]
+    fn it_rejects_non_fit_data() {
+        let a = [14, 16, 116, 6, 51, 92, 1, 0, 46, 70, 79, 79, 213, 14];
+        match FileHeader::new(&mut Cursor::new(a)) {
+            Err(FitError::BadHeader(_)) => (),
+            r => panic!("expected a bad header error, got {:?}", r),
+        }
+        match FileHeader::new(&mut Cursor::new([14, 16, 116])) {
+            Err(FitError::BadHeader(_)) => (),
+            r => panic!("expected a bad header error, got {:?}", r),
+        }
     }
 
     #[test]
     fn it_reads_header_byte_compressed_header() {
         let a = [208];
         let mut c = Cursor::new(a);
-        let h = HeaderByte::new(&mut c);
+        let h = HeaderByte::new(&mut c).unwrap();
         assert_eq!(
             h,
             HeaderByte {
@@ -549,7 +378,7 @@ mod tests {
     fn it_reads_header_byte_normal() {
         let a = [14];
         let mut c = Cursor::new(a);
-        let h = HeaderByte::new(&mut c);
+        let h = HeaderByte::new(&mut c).unwrap();
         assert_eq!(
             h,
             HeaderByte {
@@ -569,7 +398,7 @@ mod tests {
             0,
         ];
         let mut c = Cursor::new(a);
-        let def = DefinitionRecord::new(&mut c, false);
+        let def = DefinitionRecord::new(&mut c, false).unwrap();
         let comp = DefinitionRecord {
             endianness: Endianness::Little,
             global_message_number: 0,
@@ -615,9 +444,19 @@ mod tests {
         assert_eq!(def, comp);
     }
 
+    #[test]
+    fn it_rejects_unknown_endianness() {
+        let a = vec![0, 2, 0, 0, 0];
+        let mut c = Cursor::new(a);
+        match DefinitionRecord::new(&mut c, false) {
+            Err(FitError::BadDefinition(_)) => (),
+            r => panic!("expected a bad definition error, got {:?}", r),
+        }
+    }
+
     #[test]
     fn it_reads_field_definition() {
-        let fda = FieldDefinition::new(&mut Cursor::new([254, 2, 132]));
+        let fda = FieldDefinition::new(&mut Cursor::new([254, 2, 132])).unwrap();
         assert_eq!(
             fda,
             FieldDefinition {
@@ -626,7 +465,7 @@ mod tests {
                 base_type: 4
             }
         );
-        let fdb = FieldDefinition::new(&mut Cursor::new([102, 4, 2]));
+        let fdb = FieldDefinition::new(&mut Cursor::new([102, 4, 2])).unwrap();
         assert_eq!(
             fdb,
             FieldDefinition {
@@ -636,4 +475,457 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn it_reads_arrays_of_every_base_type() {
+        let a = [0x7F, 0xFF, 0x00, 0x80, 0x01, 0x00];
+        let v = read_next_field(6, 3, Endianness::Little, &mut Cursor::new(a)).unwrap();
+        assert_eq!(v, Some(Value::ArrI16(vec![0x7FFF, 0x0080, 0x0001])));
+
+        let a = [0x00, 0x00, 0x80, 0x3F, 0x00, 0x00, 0x00, 0x40];
+        let v = read_next_field(8, 8, Endianness::Little, &mut Cursor::new(a)).unwrap();
+        assert_eq!(v, Some(Value::ArrF32(vec![1.0, 2.0])));
+
+        let v = read_next_field(3, 13, Endianness::Little, &mut Cursor::new([1, 0xFF, 3])).unwrap();
+        assert_eq!(v, Some(Value::Bytes(vec![1, 0xFF, 3])));
+
+        let v = read_next_field(2, 1, Endianness::Little, &mut Cursor::new([0xFF, 0x7F])).unwrap();
+        assert_eq!(v, Some(Value::ArrI8(vec![-1, 0x7F])));
+    }
+
+    #[test]
+    fn it_drops_arrays_with_no_valid_elements() {
+        let a = [0xFF, 0xFF, 0xFF, 0x7F, 0xFF, 0xFF, 0xFF, 0x7F];
+        let v = read_next_field(8, 5, Endianness::Little, &mut Cursor::new(a)).unwrap();
+        assert_eq!(v, None);
+
+        let mut c = Cursor::new([0x00; 17]);
+        let v = read_next_field(17, 12, Endianness::Big, &mut c).unwrap();
+        assert_eq!(v, None);
+        assert_eq!(c.position(), 17);
+
+        let v = read_next_field(4, 7, Endianness::Little, &mut Cursor::new([0x00; 4])).unwrap();
+        assert_eq!(v, None);
+    }
+
+    #[test]
+    fn it_keeps_the_positions_of_invalid_array_elements() {
+        // a record with speed_1s, a uint8 array with a scale of 16, as its only field
+        let definition = [0x40, 0, 0, 20, 0, 1, 17, 3, 2];
+        let data = [0x00, 16, 0xFF, 32];
+        let decode = |options: DecodeOptions| {
+            let mut decoder = Decoder::default();
+            *decoder.options_mut() = options;
+            decoder
+                .decode_record(&mut Cursor::new(definition), 0)
+                .unwrap();
+            decoder
+                .decode_record(&mut Cursor::new(data), 9)
+                .unwrap()
+                .unwrap()
+        };
+
+        let m = decode(DecodeOptions::new().scale(false));
+        let field = m.field_by_name("speed_1s").unwrap();
+        assert_eq!(field.value, Value::ArrU8(vec![16, 0xFF, 32]));
+        assert_eq!(field.invalid_elements, vec![1]);
+
+        let m = decode(DecodeOptions::new());
+        match m.get("speed_1s") {
+            Some(Value::ArrF64(v)) => {
+                assert_eq!((v.len(), v[0], v[2]), (3, 1.0, 2.0));
+                assert!(v[1].is_nan());
+            }
+            v => panic!("expected a scaled array, got {:?}", v),
+        }
+    }
+
+    #[test]
+    fn it_expands_compressed_timestamps() {
+        assert_eq!(expand_timestamp(0x3B, 0x1D), Some(0x3D));
+        assert_eq!(expand_timestamp(0x3B, 0x1B), Some(0x3B));
+        // an offset lower than the last timestamp's low bits means it rolled over
+        assert_eq!(expand_timestamp(0x3B, 0x02), Some(0x42));
+        assert_eq!(expand_timestamp(0xFFFF_FFE0, 0x1F), Some(0xFFFF_FFFF));
+        // unless it would roll over past the largest timestamp there is
+        assert_eq!(expand_timestamp(0xFFFF_FFFE, 0x00), None);
+    }
+
+    #[test]
+    fn it_replaces_redefined_local_messages() {
+        let mut decoder = Decoder::default();
+        let a = [0x43, 0, 0, 0, 0, 1, 0, 1, 0];
+        assert!(decoder
+            .decode_record(&mut Cursor::new(a), 0)
+            .unwrap()
+            .is_none());
+        let a = [0x43, 0, 0, 20, 0, 1, 3, 1, 2];
+        assert!(decoder
+            .decode_record(&mut Cursor::new(a), 0)
+            .unwrap()
+            .is_none());
+
+        let definitions = decoder.definitions();
+        assert_eq!(definitions.len(), 16);
+        assert_eq!(definitions[3].as_ref().unwrap().global_message_number, 20);
+        assert!(definitions
+            .iter()
+            .enumerate()
+            .all(|(i, d)| d.is_some() == (i == 3)));
+    }
+
+    #[test]
+    fn it_rejects_undefined_local_messages() {
+        let mut decoder = Decoder::default();
+        match decoder.decode_record(&mut Cursor::new([0x05, 1, 2, 3]), 0) {
+            Err(FitError::UndefinedLocalMessage(5)) => (),
+            r => panic!("expected an undefined local message error, got {:?}", r),
+        }
+    }
+
+    #[test]
+    fn it_reads_developer_data_ids() {
+        let values = vec![
+            DataField::new(1, Value::Bytes((0..16).collect())),
+            DataField::new(2, Value::U16(255)),
+            DataField::new(3, Value::U8(2)),
+            DataField::new(4, Value::U32(12)),
+        ];
+        let d = DeveloperDataId::new(&values).unwrap();
+        assert_eq!(d.developer_data_index, 2);
+        assert_eq!(d.developer_id, None);
+        assert_eq!(
+            (d.manufacturer_id, d.application_version),
+            (Some(255), Some(12))
+        );
+        assert_eq!(
+            d.application_uuid().unwrap(),
+            "00010203-0405-0607-0809-0a0b0c0d0e0f"
+        );
+        match DeveloperDataId::new(&values[..2]) {
+            Err(FitError::BadFieldDescription(_)) => (),
+            r => panic!("expected a bad field description error, got {:?}", r),
+        }
+    }
+
+    #[test]
+    fn it_names_profile_fields() {
+        let info = crate::profile::field_info(20, 3).unwrap();
+        assert_eq!((info.name, info.units), ("heart_rate", "bpm"));
+        assert_eq!(
+            crate::profile::field_info(18, 253).unwrap().name,
+            "timestamp"
+        );
+        assert_eq!(crate::profile::field_info(20, 250), None);
+        assert_eq!(crate::profile::field_info(65280, 0), None);
+
+        // every message in the profile is covered, not only activity files' messages
+        let names: Vec<_> = [(26, 8), (31, 5), (33, 0), (160, 0), (216, 2), (393, 0)]
+            .iter()
+            .map(|(m, f)| crate::profile::field_info(*m, *f).map(|info| info.name))
+            .collect();
+        let expected = [
+            "wkt_name",
+            "name",
+            "timer_time",
+            "timestamp_ms",
+            "time_in_hr_zone",
+            "depth",
+        ];
+        assert_eq!(names, expected.iter().map(|n| Some(*n)).collect::<Vec<_>>());
+
+        // a set's timestamp is field 254, which is message_index in other messages
+        assert_eq!(
+            crate::profile::field_info(225, 254).unwrap().name,
+            "timestamp"
+        );
+        assert_eq!(
+            crate::profile::field_info(225, 10).unwrap().name,
+            "message_index"
+        );
+    }
+
+    #[test]
+    fn it_lists_every_field_fitsdk_knows() {
+        for n in 0..=0xFFFE {
+            let kind = fitsdk::get_message_type(n);
+            if kind == fitsdk::MessageType::None {
+                continue;
+            }
+            let fields = fitsdk::get_field_type_fn(kind);
+            for f in 0..=255 {
+                if let fitsdk::FieldType::None = fields(f) {
+                    continue;
+                }
+                let info = crate::profile::field_info(n, f);
+                assert!(info.is_some(), "field {} of message {} is missing", f, n);
+            }
+        }
+    }
+
+    #[test]
+    fn it_expands_components() {
+        let values = vec![
+            DataField::new(8, Value::Bytes(vec![0xF4, 0x01, 0x32])),
+            DataField::new(2, Value::U16(3000)),
+        ];
+        let expanded = expand_components(20, &values, &mut HashMap::new());
+        assert_eq!(
+            expanded,
+            vec![
+                DataField::new(6, Value::U16(5000)),
+                DataField::new(5, Value::U32(5000)),
+                DataField::new(78, Value::U32(3000)),
+                DataField::new(73, Value::U32(5000)),
+            ]
+        );
+
+        // the fields a component expands to are expanded in turn, here data16 to data and
+        // data to the gears of a rear_gear_change event
+        let values = vec![
+            DataField::new(0, Value::U8(42)),
+            DataField::new(2, Value::U16(0x1006)),
+        ];
+        let expanded = expand_components(21, &values, &mut HashMap::new());
+        assert_eq!(
+            expanded,
+            vec![
+                DataField::new(3, Value::U32(0x1006)),
+                DataField::new(11, Value::U8(6)),
+                DataField::new(12, Value::U8(0x10)),
+                DataField::new(9, Value::U8(0)),
+                DataField::new(10, Value::U8(0)),
+            ]
+        );
+
+        // respiration_rate is copied into the finer enhanced_respiration_rate
+        let values = vec![DataField::new(99, Value::U8(15))];
+        let expanded = expand_components(20, &values, &mut HashMap::new());
+        assert_eq!(expanded, vec![DataField::new(108, Value::U16(1500))]);
+
+        // a field that was recorded directly isn't replaced by its component
+        let values = vec![
+            DataField::new(6, Value::U16(4000)),
+            DataField::new(73, Value::U32(4001)),
+        ];
+        assert!(expand_components(20, &values, &mut HashMap::new()).is_empty());
+
+        // unless it was recorded as invalid, which is only kept with invalid_values
+        let values = vec![
+            DataField::new(6, Value::U16(4000)),
+            DataField::new(73, Value::Invalid),
+        ];
+        let expanded = expand_components(20, &values, &mut HashMap::new());
+        assert_eq!(expanded, vec![DataField::new(73, Value::U32(4000))]);
+    }
+
+    #[test]
+    fn it_accumulates_repeated_components_into_arrays() {
+        let bytes = vec![
+            0xFE, 0xFF, 0xFF, 0x00, 0x10, 0x00, 0x02, 0x30, 0x00, 0x04, 0x50, 0x00,
+        ];
+        let values = vec![DataField::new(10, Value::Bytes(bytes))];
+        let mut accumulators = HashMap::new();
+        let expanded = expand_components(132, &values, &mut accumulators);
+        let expected = vec![4094, 4095, 4096, 4097, 4098, 4099, 4100, 4101];
+        assert_eq!(expanded, vec![DataField::new(9, Value::ArrU32(expected))]);
+
+        // a value recorded in full restarts the running total
+        let values = vec![DataField::new(9, Value::ArrU32(vec![10240, 20480]))];
+        assert!(expand_components(132, &values, &mut accumulators).is_empty());
+        let mut bytes = vec![0x05];
+        bytes.extend_from_slice(&[0; 11]);
+        let values = vec![DataField::new(10, Value::Bytes(bytes))];
+        let expanded = expand_components(132, &values, &mut accumulators);
+        let expected = vec![20485, 24576, 24576, 24576, 24576, 24576, 24576, 24576];
+        assert_eq!(expanded, vec![DataField::new(9, Value::ArrU32(expected))]);
+    }
+
+    #[test]
+    fn it_scales_with_the_exact_profile_scale() {
+        let fields = fitsdk::get_field_type_fn(fitsdk::MessageType::Hr);
+
+        // fractional_timestamp is scaled by 32768
+        let mut v = DataField::new(0, Value::U16(16384));
+        process_value(&mut v, 132, fields, &DecodeOptions::default());
+        assert_eq!(v.value, Value::F64(0.5));
+
+        // every element of an array is scaled
+        let mut v = DataField::new(9, Value::ArrU32(vec![1024, 1536, 2048]));
+        process_value(&mut v, 132, fields, &DecodeOptions::default());
+        assert_eq!(v.value, Value::ArrF64(vec![1.0, 1.5, 2.0]));
+
+        // altitude's offset of 500m is applied after its scale
+        let fields = fitsdk::get_field_type_fn(fitsdk::MessageType::Record);
+        let mut v = DataField::new(2, Value::U16(2600));
+        process_value(&mut v, 20, fields, &DecodeOptions::default());
+        assert_eq!(v.value, Value::F64(20.0));
+
+        // coordinates are converted from semicircles to degrees
+        let mut v = DataField::new(0, Value::I32(1 << 30));
+        process_value(&mut v, 20, fields, &DecodeOptions::default());
+        assert_eq!(v.value, Value::F64(90.0));
+    }
+
+    #[test]
+    fn it_converts_values() {
+        use std::convert::TryFrom;
+
+        assert_eq!(u16::try_from(&Value::U8(7)).unwrap(), 7);
+        assert_eq!(u8::try_from(&Value::U32(255)).unwrap(), 255);
+        assert!(u8::try_from(&Value::U32(256)).is_err());
+        assert!(u32::try_from(&Value::I8(-1)).is_err());
+        assert_eq!(
+            i64::try_from(&Value::Time(1_000_000_000)).unwrap(),
+            1_000_000_000
+        );
+        assert_eq!(f64::try_from(&Value::I16(-3)).ok(), Some(-3.0));
+        assert_eq!(String::try_from(&Value::Enum("manual")).unwrap(), "manual");
+        match i32::try_from(&Value::String("x".to_string())) {
+            Err(FitError::UnexpectedValue { expected, .. }) => assert_eq!(expected, "i32"),
+            r => panic!("expected an unexpected value error, got {:?}", r),
+        }
+
+        assert_eq!(Value::U64(3).as_f64(), Some(3.0));
+        assert_eq!(Value::Time(3).as_f64(), None);
+        assert_eq!(Value::String("abc".to_string()).as_str(), Some("abc"));
+        let a = Value::ArrI16(vec![-1, 2, 3]);
+        assert_eq!(a.as_slice::<i16>(), Some(&[-1, 2, 3][..]));
+        assert_eq!(a.as_slice::<u16>(), None);
+        assert!(a.is_array() && !Value::U8(1).is_array());
+        assert_eq!(
+            (a.len(), Value::U8(1).len(), Value::Invalid.len()),
+            (3, 1, 0)
+        );
+        assert!(Value::Invalid.is_empty());
+    }
+
+    fn decode_all(records: &[&[u8]]) -> Vec<Message> {
+        let mut decoder = Decoder::default();
+        records
+            .iter()
+            .filter_map(|r| decoder.decode_record(&mut Cursor::new(r), 0).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn it_adds_fractions_of_a_second_to_timestamps() {
+        // an hr message with a timestamp and a fractional_timestamp of half a second
+        let m = decode_all(&[
+            &[0x40, 0, 0, 132, 0, 2, 253, 4, 0x86, 0, 2, 0x84],
+            &[0x00, 0x00, 0xCA, 0x9A, 0x3B, 0x00, 0x40],
+        ]);
+        let t = m[0].timestamp();
+        assert_eq!(t, Timestamp::new(1_000_000_000 + PSEUDO_EPOCH, 500_000_000));
+
+        // a gps_metadata message with a timestamp and a timestamp_ms of 250
+        let m = decode_all(&[
+            &[0x40, 0, 0, 160, 0, 2, 253, 4, 0x86, 0, 2, 0x84],
+            &[0x00, 0x00, 0xCA, 0x9A, 0x3B, 0xFA, 0x00],
+        ]);
+        let t = m[0].timestamp();
+        assert_eq!(t, Timestamp::new(1_000_000_000 + PSEUDO_EPOCH, 250_000_000));
+        assert_eq!(
+            Timestamp::new(1, 1_500_000_000),
+            Timestamp::new(2, 500_000_000)
+        );
+        assert_eq!(Timestamp::new(u32::MAX, 1_000_000_000), None);
+    }
+
+    #[test]
+    fn it_keeps_system_times_apart_from_utc_times() {
+        // a file_id whose time_created is only 1000 seconds after the device was turned on
+        let m = decode_all(&[
+            &[0x40, 0, 0, 0, 0, 1, 4, 4, 0x86],
+            &[0x00, 0xE8, 0x03, 0x00, 0x00],
+        ]);
+        assert_eq!(m[0].get("time_created"), Some(&Value::SystemTime(1000)));
+
+        // and one too late to count from the Unix epoch in a u32
+        let m = decode_all(&[
+            &[0x40, 0, 0, 0, 0, 1, 4, 4, 0x86],
+            &[0x00, 0xFE, 0xFF, 0xFF, 0xFF],
+        ]);
+        assert_eq!(m[0].get("time_created"), Some(&Value::Invalid));
+    }
+
+    #[test]
+    fn it_expands_16_bit_timestamps() {
+        assert_eq!(expand_timestamp_16(0x0001_FFF0, 0x0010), Some(0x0002_0010));
+        assert_eq!(expand_timestamp_16(0xFFFF_FFF0, 0x0010), None);
+
+        // monitoring messages with a full timestamp, then only the low 16 bits of one
+        let m = decode_all(&[
+            &[0x40, 0, 0, 55, 0, 1, 253, 4, 0x86],
+            &[0x00, 0x00, 0xCA, 0x9A, 0x3B],
+            &[0x41, 0, 0, 55, 0, 1, 26, 2, 0x84],
+            &[0x01, 0x64, 0xCA],
+        ]);
+        let expected = Value::Time(1_000_000_100 + PSEUDO_EPOCH);
+        assert_eq!(m[1].get("timestamp"), Some(&expected));
+    }
+
+    #[test]
+    fn it_waits_for_a_full_timestamp() {
+        // a monitoring message with only a timestamp_16, and a record with a compressed
+        // timestamp header, before any full timestamp has been seen
+        let m = decode_all(&[
+            &[0x40, 0, 0, 55, 0, 1, 26, 2, 0x84],
+            &[0x00, 0x64, 0xCA],
+            &[0x41, 0, 0, 20, 0, 1, 3, 1, 0x02],
+            &[0xA5, 0x64],
+        ]);
+        assert_eq!(m.len(), 2);
+        assert!(m.iter().all(|m| m.get("timestamp").is_none()));
+
+        // the record's timestamp counts on from the first full one
+        let m = decode_all(&[
+            &[0x40, 0, 0, 20, 0, 2, 253, 4, 0x86, 3, 1, 0x02],
+            &[0x00, 0x00, 0xCA, 0x9A, 0x3B, 0x64],
+            &[0x80, 0x64],
+        ]);
+        let expected = Value::Time(1_000_000_000 + PSEUDO_EPOCH);
+        assert_eq!(m[1].get("timestamp"), Some(&expected));
+    }
+
+    #[test]
+    fn it_takes_the_utc_offset_from_local_timestamps() {
+        let mut decoder = Decoder::default();
+        let mut decode = |r: &[u8]| decoder.decode_record(&mut Cursor::new(r), 0).unwrap();
+        let local = 1_000_007_200 + PSEUDO_EPOCH;
+
+        // a monitoring message with only a local_timestamp, before the offset is known
+        decode(&[0x40, 0, 0, 55, 0, 1, 11, 4, 0x86]);
+        let m = decode(&[0x00, 0x20, 0xE6, 0x9A, 0x3B]).unwrap();
+        let t = LocalTime {
+            secs: local,
+            utc_offset: None,
+        };
+        assert_eq!(m.get("local_timestamp"), Some(&Value::LocalTime(t)));
+        assert_eq!(t.to_utc(), None);
+
+        // an activity message with a timestamp and a local_timestamp two hours ahead of it
+        decode(&[0x41, 0, 0, 34, 0, 2, 253, 4, 0x86, 5, 4, 0x86]);
+        let m = decode(&[0x01, 0x00, 0xCA, 0x9A, 0x3B, 0x20, 0xE6, 0x9A, 0x3B]).unwrap();
+        let t = LocalTime {
+            secs: local,
+            utc_offset: Some(7200),
+        };
+        assert_eq!(m.get("local_timestamp"), Some(&Value::LocalTime(t)));
+        assert_eq!(t.to_utc(), Timestamp::new(1_000_000_000 + PSEUDO_EPOCH, 0));
+        assert_eq!(decoder.utc_offset(), Some(7200));
+
+        // one whose local_timestamp is a day ahead, which no time zone is, is ignored
+        let m = decode(&[0x01, 0x00, 0xCA, 0x9A, 0x3B, 0x80, 0x1B, 0x9C, 0x3B]).unwrap();
+        assert_eq!(decoder.utc_offset(), Some(7200));
+        let t = LocalTime {
+            secs: 1_000_086_400 + PSEUDO_EPOCH,
+            utc_offset: Some(7200),
+        };
+        assert_eq!(m.get("local_timestamp"), Some(&Value::LocalTime(t)));
+
+        // and one too late to count from the Unix epoch in a u32 is invalid
+        let m = decode(&[0x01, 0x00, 0xCA, 0x9A, 0x3B, 0xFE, 0xFF, 0xFF, 0xFF]).unwrap();
+        assert_eq!(m.get("local_timestamp"), Some(&Value::Invalid));
+    }
 }
@@ -0,0 +1,555 @@
+//! Structs for the messages in the FIT profile, with a field of the right type for each of the
+//! message's fields, so they can be used without matching on `Value`s:
+//!
+//! ```no_run
+//! use std::convert::TryFrom;
+//! use fit::messages::Record;
+//!
+//! for m in fit::Fit::open("fits/2913547417.fit")? {
+//!     if let Ok(record) = Record::try_from(&m) {
+//!         println!("heart rate: {:?}", record.heart_rate);
+//!     }
+//! }
+//! # Ok::<(), fit::FitError>(())
+//! ```
+//!
+//! Each field is `None` if the message doesn't have it, or it can't be converted to the
+//! field's type. The `f64` fields hold physical quantities in the units the profile gives
+//! them, so they're `None` if the value wasn't converted to those units, such as when scaling
+//! has been turned off with `DecodeOptions::scale` or positions have been left in semicircles
+//! with `DecodeOptions::coordinates`.
+//!
+//! A field whose meaning depends on another field, such as `product`, is held both under its
+//! own name and under the subfield it was resolved to, such as `garmin_product`.
+
+use std::convert::TryFrom;
+
+use fitsdk::MessageType;
+
+use crate::{ArrayElement, FitError, LocalTime, Message, Timestamp, Value};
+
+//////////
+//// FromValue
+//////////
+
+/// Converts a field's value to the type a message struct holds it as.
+trait FromValue: Sized {
+    fn from_value(v: &Value) -> Option<Self>;
+}
+macro_rules! from_value {
+    ($($t:ty),*) => {
+        $(
+            impl FromValue for $t {
+                fn from_value(v: &Value) -> Option<Self> {
+                    <$t>::try_from(v).ok()
+                }
+            }
+        )*
+    };
+}
+from_value!(u8, i8, u16, u32, String, Timestamp, LocalTime);
+// a raw integer isn't in the field's units yet, so only values that have been scaled count
+impl FromValue for f64 {
+    fn from_value(v: &Value) -> Option<Self> {
+        match v {
+            Value::F64(f) => Some(*f),
+            Value::F32(f) => Some(f64::from(*f)),
+            _ => None,
+        }
+    }
+}
+impl FromValue for Value {
+    fn from_value(v: &Value) -> Option<Self> {
+        Some(v.clone())
+    }
+}
+// an array with only one element is decoded as a single value
+impl<T> FromValue for Vec<T>
+where
+    T: ArrayElement + Clone + FromValue,
+{
+    fn from_value(v: &Value) -> Option<Self> {
+        match v.as_slice::<T>() {
+            Some(a) => Some(a.to_vec()),
+            None => T::from_value(v).map(|v| vec![v]),
+        }
+    }
+}
+
+macro_rules! messages {
+    ($($(#[$attr:meta])* $name:ident { $($field:ident: $t:ty,)* })*) => {
+        $(
+            $(#[$attr])*
+            #[derive(Clone, Debug, Default, PartialEq)]
+            pub struct $name {
+                $(pub $field: Option<$t>,)*
+            }
+            impl TryFrom<&Message> for $name {
+                type Error = FitError;
+                fn try_from(m: &Message) -> Result<Self, FitError> {
+                    if m.kind != MessageType::$name {
+                        return Err(FitError::UnexpectedMessage {
+                            expected: MessageType::$name,
+                            found: m.kind,
+                        });
+                    }
+                    Ok(Self {
+                        $($field: m
+                            .get(stringify!($field).trim_start_matches("r#"))
+                            .and_then(FromValue::from_value),)*
+                    })
+                }
+            }
+        )*
+    };
+}
+
+//////////
+//// Messages
+//////////
+
+messages! {
+    /// Identifies the type of a FIT file and the device that created it.
+    FileId {
+        r#type: String,
+        manufacturer: String,
+        product: Value,
+        garmin_product: String,
+        favero_product: String,
+        serial_number: u32,
+        time_created: Timestamp,
+        number: u16,
+        product_name: String,
+    }
+    /// The software and hardware versions of the device that created the file.
+    FileCreator {
+        software_version: u16,
+        hardware_version: u8,
+    }
+    /// A summary of the whole activity, usually the last message in the file.
+    Activity {
+        timestamp: Timestamp,
+        total_timer_time: f64,
+        num_sessions: u16,
+        r#type: String,
+        event: String,
+        event_type: String,
+        local_timestamp: LocalTime,
+        event_group: u8,
+    }
+    /// A summary of one sport in an activity.
+    Session {
+        timestamp: Timestamp,
+        message_index: u16,
+        event: String,
+        event_type: String,
+        start_time: Timestamp,
+        start_position_lat: f64,
+        start_position_long: f64,
+        sport: String,
+        sub_sport: String,
+        total_elapsed_time: f64,
+        total_timer_time: f64,
+        total_distance: f64,
+        total_cycles: u32,
+        total_calories: u16,
+        total_fat_calories: u16,
+        avg_speed: f64,
+        max_speed: f64,
+        avg_heart_rate: u8,
+        max_heart_rate: u8,
+        avg_cadence: u8,
+        max_cadence: u8,
+        avg_power: u16,
+        max_power: u16,
+        total_ascent: u16,
+        total_descent: u16,
+        total_training_effect: f64,
+        first_lap_index: u16,
+        num_laps: u16,
+        event_group: u8,
+        trigger: String,
+        nec_lat: f64,
+        nec_long: f64,
+        swc_lat: f64,
+        swc_long: f64,
+        num_lengths: u16,
+        normalized_power: u16,
+        training_stress_score: f64,
+        intensity_factor: f64,
+        left_right_balance: u16,
+        end_position_lat: f64,
+        end_position_long: f64,
+        avg_stroke_count: f64,
+        avg_stroke_distance: f64,
+        swim_stroke: String,
+        pool_length: f64,
+        threshold_power: u16,
+        pool_length_unit: String,
+        num_active_lengths: u16,
+        total_work: u32,
+        avg_altitude: f64,
+        max_altitude: f64,
+        gps_accuracy: u8,
+        avg_grade: f64,
+        avg_pos_grade: f64,
+        avg_neg_grade: f64,
+        max_pos_grade: f64,
+        max_neg_grade: f64,
+        avg_temperature: i8,
+        max_temperature: i8,
+        total_moving_time: f64,
+        avg_pos_vertical_speed: f64,
+        avg_neg_vertical_speed: f64,
+        max_pos_vertical_speed: f64,
+        max_neg_vertical_speed: f64,
+        min_heart_rate: u8,
+        time_in_hr_zone: Vec<f64>,
+        time_in_speed_zone: Vec<f64>,
+        time_in_cadence_zone: Vec<f64>,
+        time_in_power_zone: Vec<f64>,
+        avg_lap_time: f64,
+        best_lap_index: u16,
+        min_altitude: f64,
+        player_score: u16,
+        opponent_score: u16,
+        opponent_name: String,
+        stroke_count: Vec<u16>,
+        zone_count: Vec<u16>,
+        max_ball_speed: f64,
+        avg_ball_speed: f64,
+        avg_vertical_oscillation: f64,
+        avg_stance_time_percent: f64,
+        avg_stance_time: f64,
+        avg_fractional_cadence: f64,
+        max_fractional_cadence: f64,
+        total_fractional_cycles: f64,
+        avg_left_torque_effectiveness: f64,
+        avg_right_torque_effectiveness: f64,
+        avg_left_pedal_smoothness: f64,
+        avg_right_pedal_smoothness: f64,
+        avg_combined_pedal_smoothness: f64,
+        sport_index: u8,
+        time_standing: f64,
+        stand_count: u16,
+        avg_left_pco: i8,
+        avg_right_pco: i8,
+        avg_left_power_phase: Vec<f64>,
+        avg_left_power_phase_peak: Vec<f64>,
+        avg_right_power_phase: Vec<f64>,
+        avg_right_power_phase_peak: Vec<f64>,
+        avg_power_position: Vec<u16>,
+        max_power_position: Vec<u16>,
+        avg_cadence_position: Vec<u8>,
+        max_cadence_position: Vec<u8>,
+        enhanced_avg_speed: f64,
+        enhanced_max_speed: f64,
+        enhanced_avg_altitude: f64,
+        enhanced_min_altitude: f64,
+        enhanced_max_altitude: f64,
+        avg_lev_motor_power: u16,
+        max_lev_motor_power: u16,
+        lev_battery_consumption: f64,
+        avg_vertical_ratio: f64,
+        avg_stance_time_balance: f64,
+        avg_step_length: f64,
+        total_anaerobic_training_effect: f64,
+        avg_vam: f64,
+        total_grit: f64,
+        total_flow: f64,
+        jump_count: u16,
+        avg_grit: f64,
+        avg_flow: f64,
+        total_fractional_ascent: f64,
+        total_fractional_descent: f64,
+        avg_core_temperature: f64,
+        min_core_temperature: f64,
+        max_core_temperature: f64,
+    }
+    /// A summary of one lap of a session.
+    Lap {
+        timestamp: Timestamp,
+        message_index: u16,
+        event: String,
+        event_type: String,
+        start_time: Timestamp,
+        start_position_lat: f64,
+        start_position_long: f64,
+        end_position_lat: f64,
+        end_position_long: f64,
+        total_elapsed_time: f64,
+        total_timer_time: f64,
+        total_distance: f64,
+        total_cycles: u32,
+        total_calories: u16,
+        total_fat_calories: u16,
+        avg_speed: f64,
+        max_speed: f64,
+        avg_heart_rate: u8,
+        max_heart_rate: u8,
+        avg_cadence: u8,
+        max_cadence: u8,
+        avg_power: u16,
+        max_power: u16,
+        total_ascent: u16,
+        total_descent: u16,
+        intensity: String,
+        lap_trigger: String,
+        sport: String,
+        event_group: u8,
+        num_lengths: u16,
+        normalized_power: u16,
+        left_right_balance: u16,
+        first_length_index: u16,
+        avg_stroke_distance: f64,
+        swim_stroke: String,
+        sub_sport: String,
+        num_active_lengths: u16,
+        total_work: u32,
+        avg_altitude: f64,
+        max_altitude: f64,
+        gps_accuracy: u8,
+        avg_grade: f64,
+        avg_pos_grade: f64,
+        avg_neg_grade: f64,
+        max_pos_grade: f64,
+        max_neg_grade: f64,
+        avg_temperature: i8,
+        max_temperature: i8,
+        total_moving_time: f64,
+        avg_pos_vertical_speed: f64,
+        avg_neg_vertical_speed: f64,
+        max_pos_vertical_speed: f64,
+        max_neg_vertical_speed: f64,
+        time_in_hr_zone: Vec<f64>,
+        time_in_speed_zone: Vec<f64>,
+        time_in_cadence_zone: Vec<f64>,
+        time_in_power_zone: Vec<f64>,
+        repetition_num: u16,
+        min_altitude: f64,
+        min_heart_rate: u8,
+        wkt_step_index: u16,
+        opponent_score: u16,
+        stroke_count: Vec<u16>,
+        zone_count: Vec<u16>,
+        avg_vertical_oscillation: f64,
+        avg_stance_time_percent: f64,
+        avg_stance_time: f64,
+        avg_fractional_cadence: f64,
+        max_fractional_cadence: f64,
+        total_fractional_cycles: f64,
+        player_score: u16,
+        avg_left_torque_effectiveness: f64,
+        avg_right_torque_effectiveness: f64,
+        avg_left_pedal_smoothness: f64,
+        avg_right_pedal_smoothness: f64,
+        avg_combined_pedal_smoothness: f64,
+        time_standing: f64,
+        stand_count: u16,
+        avg_left_pco: i8,
+        avg_right_pco: i8,
+        avg_left_power_phase: Vec<f64>,
+        avg_left_power_phase_peak: Vec<f64>,
+        avg_right_power_phase: Vec<f64>,
+        avg_right_power_phase_peak: Vec<f64>,
+        avg_power_position: Vec<u16>,
+        max_power_position: Vec<u16>,
+        avg_cadence_position: Vec<u8>,
+        max_cadence_position: Vec<u8>,
+        enhanced_avg_speed: f64,
+        enhanced_max_speed: f64,
+        enhanced_avg_altitude: f64,
+        enhanced_min_altitude: f64,
+        enhanced_max_altitude: f64,
+        avg_lev_motor_power: u16,
+        max_lev_motor_power: u16,
+        lev_battery_consumption: f64,
+        avg_vertical_ratio: f64,
+        avg_stance_time_balance: f64,
+        avg_step_length: f64,
+        avg_vam: f64,
+        total_grit: f64,
+        total_flow: f64,
+        jump_count: u16,
+        avg_grit: f64,
+        avg_flow: f64,
+        total_fractional_ascent: f64,
+        total_fractional_descent: f64,
+        avg_core_temperature: f64,
+        min_core_temperature: f64,
+        max_core_temperature: f64,
+    }
+    /// A single length of a swimming pool.
+    Length {
+        timestamp: Timestamp,
+        message_index: u16,
+        event: String,
+        event_type: String,
+        start_time: Timestamp,
+        total_elapsed_time: f64,
+        total_timer_time: f64,
+        total_strokes: u16,
+        avg_speed: f64,
+        swim_stroke: String,
+        avg_swimming_cadence: u8,
+        event_group: u8,
+        total_calories: u16,
+        length_type: String,
+        player_score: u16,
+        opponent_score: u16,
+        stroke_count: Vec<u16>,
+        zone_count: Vec<u16>,
+    }
+    /// A sample of the data recorded during an activity.
+    Record {
+        timestamp: Timestamp,
+        position_lat: f64,
+        position_long: f64,
+        altitude: f64,
+        heart_rate: u8,
+        cadence: u8,
+        distance: f64,
+        speed: f64,
+        power: u16,
+        compressed_speed_distance: Value,
+        grade: f64,
+        resistance: u8,
+        time_from_course: f64,
+        cycle_length: f64,
+        temperature: i8,
+        speed_1s: Vec<f64>,
+        cycles: u8,
+        total_cycles: u32,
+        compressed_accumulated_power: u16,
+        accumulated_power: u32,
+        left_right_balance: u8,
+        gps_accuracy: u8,
+        vertical_speed: f64,
+        calories: u16,
+        vertical_oscillation: f64,
+        stance_time_percent: f64,
+        stance_time: f64,
+        activity_type: String,
+        left_torque_effectiveness: f64,
+        right_torque_effectiveness: f64,
+        left_pedal_smoothness: f64,
+        right_pedal_smoothness: f64,
+        combined_pedal_smoothness: f64,
+        time128: f64,
+        stroke_type: String,
+        zone: u8,
+        ball_speed: f64,
+        cadence256: f64,
+        fractional_cadence: f64,
+        total_hemoglobin_conc: f64,
+        total_hemoglobin_conc_min: f64,
+        total_hemoglobin_conc_max: f64,
+        saturated_hemoglobin_percent: f64,
+        saturated_hemoglobin_percent_min: f64,
+        saturated_hemoglobin_percent_max: f64,
+        device_index: u8,
+        left_pco: i8,
+        right_pco: i8,
+        left_power_phase: Vec<f64>,
+        left_power_phase_peak: Vec<f64>,
+        right_power_phase: Vec<f64>,
+        right_power_phase_peak: Vec<f64>,
+        enhanced_speed: f64,
+        enhanced_altitude: f64,
+        battery_soc: f64,
+        motor_power: u16,
+        vertical_ratio: f64,
+        stance_time_balance: f64,
+        step_length: f64,
+        absolute_pressure: u32,
+        depth: f64,
+        next_stop_depth: f64,
+        next_stop_time: u32,
+        time_to_surface: u32,
+        ndl_time: u32,
+        cns_load: u8,
+        n2_load: u16,
+        grit: f64,
+        flow: f64,
+        ebike_travel_range: u16,
+        ebike_battery_level: u8,
+        ebike_assist_mode: u8,
+        ebike_assist_level_percent: u8,
+        core_temperature: f64,
+    }
+    /// Something that happened during an activity, such as the timer starting or a gear change.
+    Event {
+        timestamp: Timestamp,
+        event: String,
+        event_type: String,
+        data16: u16,
+        data: Value,
+        timer_trigger: String,
+        course_point_index: u16,
+        battery_level: f64,
+        virtual_partner_speed: f64,
+        hr_high_alert: u8,
+        hr_low_alert: u8,
+        speed_high_alert: f64,
+        speed_low_alert: f64,
+        cad_high_alert: u16,
+        cad_low_alert: u16,
+        power_high_alert: u16,
+        power_low_alert: u16,
+        time_duration_alert: f64,
+        distance_duration_alert: f64,
+        calorie_duration_alert: u32,
+        fitness_equipment_state: String,
+        sport_point: u32,
+        gear_change_data: u32,
+        rider_position: String,
+        comm_timeout: String,
+        radar_threat_alert: u32,
+        event_group: u8,
+        score: u16,
+        opponent_score: u16,
+        front_gear_num: u8,
+        front_gear: u8,
+        rear_gear_num: u8,
+        rear_gear: u8,
+        device_index: u8,
+        radar_threat_level_max: String,
+        radar_threat_count: u8,
+    }
+    /// The device that recorded the file, or one of the sensors it was connected to.
+    DeviceInfo {
+        timestamp: Timestamp,
+        device_index: u8,
+        device_type: Value,
+        antplus_device_type: String,
+        ant_device_type: u8,
+        manufacturer: String,
+        serial_number: u32,
+        product: Value,
+        garmin_product: String,
+        favero_product: String,
+        software_version: f64,
+        hardware_version: u8,
+        cum_operating_time: u32,
+        battery_voltage: f64,
+        battery_status: String,
+        sensor_position: String,
+        descriptor: String,
+        ant_transmission_type: u8,
+        ant_device_number: u16,
+        ant_network: String,
+        source_type: String,
+        product_name: String,
+        battery_level: u8,
+    }
+    /// The time between heart beats.
+    Hrv {
+        time: Vec<f64>,
+    }
+    /// The sport that the settings in a file are for.
+    Sport {
+        sport: String,
+        sub_sport: String,
+        name: String,
+    }
+}
@@ -0,0 +1,84 @@
+//////////
+//// DecodeOptions
+//////////
+
+/// Controls how much the decoder interprets the values it reads. The defaults give values in
+/// their physical units, with coordinates in degrees and components expanded, and drop
+/// anything that can't be interpreted.
+///
+/// ```no_run
+/// let options = fit::DecodeOptions::new().scale(false).invalid_values(true);
+/// let f = fit::Fit::open("fits/2913547417.fit")?.options(options);
+/// # Ok::<(), fit::FitError>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct DecodeOptions {
+    pub(crate) scale: bool,
+    pub(crate) coordinates: bool,
+    pub(crate) invalid_values: bool,
+    pub(crate) unknown_messages: bool,
+    pub(crate) expand_components: bool,
+    pub(crate) native_developer_fields: bool,
+    pub(crate) strict: bool,
+}
+impl Default for DecodeOptions {
+    fn default() -> Self {
+        Self {
+            scale: true,
+            coordinates: true,
+            invalid_values: false,
+            unknown_messages: false,
+            expand_components: true,
+            native_developer_fields: false,
+            strict: false,
+        }
+    }
+}
+impl DecodeOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Applies the scale and offset the profile gives a field, turning its value into an
+    /// `F64`. When disabled the raw integer that was recorded is kept.
+    pub fn scale(mut self, enabled: bool) -> Self {
+        self.scale = enabled;
+        self
+    }
+    /// Converts positions from semicircles to degrees.
+    pub fn coordinates(mut self, enabled: bool) -> Self {
+        self.coordinates = enabled;
+        self
+    }
+    /// Keeps fields holding their base type's invalid value as `Value::Invalid`, rather than
+    /// leaving them out of the message.
+    pub fn invalid_values(mut self, enabled: bool) -> Self {
+        self.invalid_values = enabled;
+        self
+    }
+    /// Yields messages that aren't in the profile, such as manufacturer-specific ones, with
+    /// their values as they were recorded.
+    pub fn unknown_messages(mut self, enabled: bool) -> Self {
+        self.unknown_messages = enabled;
+        self
+    }
+    /// Unpacks fields that the profile says are packed into the bits of other fields.
+    pub fn expand_components(mut self, enabled: bool) -> Self {
+        self.expand_components = enabled;
+        self
+    }
+    /// Adds developer fields that are described as standing in for a native field, such as
+    /// power supplied by an app, to a message's values when the device didn't record that
+    /// field itself. They're still included in `dev_values` as well.
+    pub fn native_developer_fields(mut self, enabled: bool) -> Self {
+        self.native_developer_fields = enabled;
+        self
+    }
+    /// Rejects data whose CRCs don't match with `FitError::CrcMismatch`, and trailing bytes
+    /// that aren't another FIT file, and stops decoding at the first error of any kind. Data
+    /// from `Fit::open` or `Fit::from_bytes` is verified before anything is decoded, but other
+    /// sources can only be checked against the file CRC once every record has been read.
+    pub fn strict(mut self, enabled: bool) -> Self {
+        self.strict = enabled;
+        self
+    }
+}
@@ -0,0 +1,2169 @@
+// Field names, units, scales and offsets for the messages in the FIT profile, transcribed from
+// the Profile.xlsx of FIT SDK 21.141. The tests check that every message and field fitsdk
+// knows about is listed, so when fitsdk is updated to a newer SDK this has to be updated too.
+// This is the only place the decoder takes scales and offsets from, as fitsdk only has room
+// for an `f32` scale and an `i16` offset. Fields that aren't listed here, such as those of
+// manufacturer-specific messages, are still decoded, just without a name.
+
+use fitsdk::FieldType;
+
+//////////
+//// FieldInfo
+//////////
+
+/// What the FIT profile says about a field of a message. A value is converted to its units
+/// as `value / scale - offset`.
+#[derive(Debug, PartialEq)]
+pub struct FieldInfo {
+    pub num: usize,
+    pub name: &'static str,
+    pub units: &'static str,
+    pub scale: Option<f64>,
+    pub offset: Option<f64>,
+}
+
+const fn f(num: usize, name: &'static str, units: &'static str) -> FieldInfo {
+    FieldInfo {
+        num,
+        name,
+        units,
+        scale: None,
+        offset: None,
+    }
+}
+
+const fn s(num: usize, name: &'static str, units: &'static str, scale: f64) -> FieldInfo {
+    FieldInfo {
+        num,
+        name,
+        units,
+        scale: Some(scale),
+        offset: None,
+    }
+}
+
+const fn so(
+    num: usize,
+    name: &'static str,
+    units: &'static str,
+    scale: f64,
+    offset: f64,
+) -> FieldInfo {
+    FieldInfo {
+        num,
+        name,
+        units,
+        scale: Some(scale),
+        offset: Some(offset),
+    }
+}
+
+/// Looks up a field of the message with the given global message number.
+pub fn field_info(global_message_number: u16, field_num: usize) -> Option<&'static FieldInfo> {
+    fields(global_message_number)
+        .iter()
+        .chain(COMMON)
+        .find(|f| f.num == field_num)
+}
+
+/// The number of the field with the given name in the message with the given global message
+/// number, if the message has one.
+pub fn field_num(global_message_number: u16, name: &str) -> Option<usize> {
+    fields(global_message_number)
+        .iter()
+        .chain(COMMON)
+        .find(|f| f.name == name)
+        .map(|f| f.num)
+}
+
+fn fields(global_message_number: u16) -> &'static [FieldInfo] {
+    match global_message_number {
+        0 => FILE_ID,
+        1 => CAPABILITIES,
+        2 => DEVICE_SETTINGS,
+        3 => USER_PROFILE,
+        4 => HRM_PROFILE,
+        5 => SDM_PROFILE,
+        6 => BIKE_PROFILE,
+        7 => ZONES_TARGET,
+        8 => HR_ZONE,
+        9 => POWER_ZONE,
+        10 => MET_ZONE,
+        12 => SPORT,
+        13 => TRAINING_SETTINGS,
+        15 => GOAL,
+        18 => SESSION,
+        19 => LAP,
+        20 => RECORD,
+        21 => EVENT,
+        23 => DEVICE_INFO,
+        26 => WORKOUT,
+        27 => WORKOUT_STEP,
+        28 => SCHEDULE,
+        30 => WEIGHT_SCALE,
+        31 => COURSE,
+        32 => COURSE_POINT,
+        33 => TOTALS,
+        34 => ACTIVITY,
+        35 => SOFTWARE,
+        37 => FILE_CAPABILITIES,
+        38 => MESG_CAPABILITIES,
+        39 => FIELD_CAPABILITIES,
+        49 => FILE_CREATOR,
+        51 => BLOOD_PRESSURE,
+        53 => SPEED_ZONE,
+        55 => MONITORING,
+        72 => TRAINING_FILE,
+        78 => HRV,
+        80 => ANT_RX,
+        81 => ANT_TX,
+        82 => ANT_CHANNEL_ID,
+        101 => LENGTH,
+        103 => MONITORING_INFO,
+        106 => SLAVE_DEVICE,
+        127 => CONNECTIVITY,
+        128 => WEATHER_CONDITIONS,
+        129 => WEATHER_ALERT,
+        131 => CADENCE_ZONE,
+        132 => HR,
+        142 => SEGMENT_LAP,
+        145 => MEMO_GLOB,
+        148 => SEGMENT_ID,
+        149 => SEGMENT_LEADERBOARD_ENTRY,
+        150 => SEGMENT_POINT,
+        151 => SEGMENT_FILE,
+        158 => WORKOUT_SESSION,
+        159 => WATCHFACE_SETTINGS,
+        160 => GPS_METADATA,
+        161 => CAMERA_EVENT,
+        162 => TIMESTAMP_CORRELATION,
+        164 => GYROSCOPE_DATA,
+        165 => ACCELEROMETER_DATA,
+        167 => THREE_D_SENSOR_CALIBRATION,
+        169 => VIDEO_FRAME,
+        174 => OBDII_DATA,
+        177 => NMEA_SENTENCE,
+        178 => AVIATION_ATTITUDE,
+        184 => VIDEO,
+        185 => VIDEO_TITLE,
+        186 => VIDEO_DESCRIPTION,
+        187 => VIDEO_CLIP,
+        188 => OHR_SETTINGS,
+        200 => EXD_SCREEN_CONFIGURATION,
+        201 => EXD_DATA_FIELD_CONFIGURATION,
+        202 => EXD_DATA_CONCEPT_CONFIGURATION,
+        206 => FIELD_DESCRIPTION,
+        207 => DEVELOPER_DATA_ID,
+        208 => MAGNETOMETER_DATA,
+        209 => BAROMETER_DATA,
+        210 => ONE_D_SENSOR_CALIBRATION,
+        211 => MONITORING_HR_DATA,
+        216 => TIME_IN_ZONE,
+        225 => SET,
+        227 => STRESS_LEVEL,
+        229 => MAX_MET_DATA,
+        258 => DIVE_SETTINGS,
+        259 => DIVE_GAS,
+        // dive_apnea_alarm has the same fields as dive_alarm
+        262 | 393 => DIVE_ALARM,
+        264 => EXERCISE_TITLE,
+        268 => DIVE_SUMMARY,
+        269 => SPO2_DATA,
+        275 => SLEEP_LEVEL,
+        285 => JUMP,
+        290 => BEAT_INTERVALS,
+        297 => RESPIRATION_RATE,
+        302 => HSA_ACCELEROMETER_DATA,
+        304 => HSA_STEP_DATA,
+        305 => HSA_SPO2_DATA,
+        306 => HSA_STRESS_DATA,
+        307 => HSA_RESPIRATION_DATA,
+        308 => HSA_HEART_RATE_DATA,
+        312 => SPLIT,
+        313 => SPLIT_SUMMARY,
+        314 => HSA_BODY_BATTERY_DATA,
+        315 => HSA_EVENT,
+        317 => CLIMB_PRO,
+        319 => TANK_UPDATE,
+        323 => TANK_SUMMARY,
+        346 => SLEEP_ASSESSMENT,
+        370 => HRV_STATUS_SUMMARY,
+        371 => HRV_VALUE,
+        372 => RAW_BBI,
+        375 => DEVICE_AUX_BATTERY_INFO,
+        376 => HSA_GYROSCOPE_DATA,
+        387 => CHRONO_SHOT_SESSION,
+        388 => CHRONO_SHOT_DATA,
+        389 => HSA_CONFIGURATION_DATA,
+        398 => SKIN_TEMP_OVERNIGHT,
+        409 => HSA_WRIST_TEMPERATURE_DATA,
+        _ => &[],
+    }
+}
+
+//////////
+//// Component
+//////////
+
+/// A value packed into some of the bits of another field, which is expanded into a field of
+/// its own. Components are packed one after another from the least significant bit.
+#[derive(Debug, PartialEq)]
+pub struct Component {
+    /// The field the value is expanded into.
+    pub field_num: usize,
+    pub bits: u8,
+    pub scale: f64,
+    pub offset: f64,
+    /// The base type of the field the value is expanded into.
+    pub base_type: u8,
+    /// Whether the value is a rolling counter that has to be added to a running total.
+    pub accumulate: bool,
+}
+
+const fn c(
+    field_num: usize,
+    bits: u8,
+    scale: f64,
+    offset: f64,
+    base_type: u8,
+    accumulate: bool,
+) -> Component {
+    Component {
+        field_num,
+        bits,
+        scale,
+        offset,
+        base_type,
+        accumulate,
+    }
+}
+
+/// The components packed into a field of the message with the given global message number.
+pub fn components(global_message_number: u16, field_num: usize) -> &'static [Component] {
+    COMPONENTS
+        .iter()
+        .find(|(m, f, _)| *m == global_message_number && *f == field_num)
+        .map(|(_, _, c)| *c)
+        .unwrap_or(&[])
+}
+
+/// The component a field is accumulated from, if the profile says it's sent as a rolling
+/// counter in the bits of another field of the same message.
+pub fn accumulated_from(
+    global_message_number: u16,
+    field_num: usize,
+) -> Option<&'static Component> {
+    COMPONENTS
+        .iter()
+        .filter(|(m, _, _)| *m == global_message_number)
+        .flat_map(|(_, _, c)| c.iter())
+        .find(|c| c.accumulate && c.field_num == field_num)
+}
+
+const COMPONENTS: &[(u16, usize, &[Component])] = &[
+    // session: avg_speed, max_speed, avg_altitude, max_altitude, min_altitude,
+    // avg_respiration_rate, max_respiration_rate, min_respiration_rate
+    (18, 14, &[c(124, 16, 1000.0, 0.0, 6, false)]),
+    (18, 15, &[c(125, 16, 1000.0, 0.0, 6, false)]),
+    (18, 49, &[c(126, 16, 5.0, 500.0, 6, false)]),
+    (18, 50, &[c(128, 16, 5.0, 500.0, 6, false)]),
+    (18, 71, &[c(127, 16, 5.0, 500.0, 6, false)]),
+    (18, 147, &[c(169, 8, 1.0, 0.0, 4, false)]),
+    (18, 148, &[c(170, 8, 1.0, 0.0, 4, false)]),
+    (18, 149, &[c(180, 8, 1.0, 0.0, 4, false)]),
+    // lap: avg_speed, max_speed, avg_altitude, max_altitude, min_altitude,
+    // avg_respiration_rate, max_respiration_rate
+    (19, 13, &[c(110, 16, 1000.0, 0.0, 6, false)]),
+    (19, 14, &[c(111, 16, 1000.0, 0.0, 6, false)]),
+    (19, 42, &[c(112, 16, 5.0, 500.0, 6, false)]),
+    (19, 43, &[c(114, 16, 5.0, 500.0, 6, false)]),
+    (19, 62, &[c(113, 16, 5.0, 500.0, 6, false)]),
+    (19, 147, &[c(136, 8, 1.0, 0.0, 4, false)]),
+    (19, 148, &[c(137, 8, 1.0, 0.0, 4, false)]),
+    // record: altitude, speed, compressed_speed_distance, cycles, compressed_accumulated_power,
+    // respiration_rate
+    (20, 2, &[c(78, 16, 5.0, 500.0, 6, false)]),
+    (20, 6, &[c(73, 16, 1000.0, 0.0, 6, false)]),
+    (
+        20,
+        8,
+        &[c(6, 12, 100.0, 0.0, 4, false), c(5, 12, 16.0, 0.0, 6, true)],
+    ),
+    (20, 18, &[c(19, 8, 1.0, 0.0, 6, true)]),
+    (20, 28, &[c(29, 16, 1.0, 0.0, 6, true)]),
+    (20, 99, &[c(108, 8, 1.0, 0.0, 4, false)]),
+    // event: data16
+    (21, 2, &[c(3, 16, 1.0, 0.0, 6, false)]),
+    // monitoring: current_activity_type_intensity
+    (
+        55,
+        24,
+        &[c(5, 5, 1.0, 0.0, 0, false), c(28, 3, 1.0, 0.0, 2, false)],
+    ),
+    // hr: time256, event_timestamp_12
+    (132, 1, &[c(0, 8, 256.0, 0.0, 4, false)]),
+    (
+        132,
+        10,
+        &[
+            c(9, 12, 1024.0, 0.0, 6, true),
+            c(9, 12, 1024.0, 0.0, 6, true),
+            c(9, 12, 1024.0, 0.0, 6, true),
+            c(9, 12, 1024.0, 0.0, 6, true),
+            c(9, 12, 1024.0, 0.0, 6, true),
+            c(9, 12, 1024.0, 0.0, 6, true),
+            c(9, 12, 1024.0, 0.0, 6, true),
+            c(9, 12, 1024.0, 0.0, 6, true),
+        ],
+    ),
+    // jump: speed
+    (285, 7, &[c(8, 16, 1000.0, 0.0, 6, false)]),
+];
+
+//////////
+//// SubField
+//////////
+
+/// An alternative meaning of a field, which applies when a reference field in the same message
+/// has one of the given raw values.
+#[derive(Debug, PartialEq)]
+pub struct SubField {
+    pub name: &'static str,
+    pub units: &'static str,
+    pub scale: Option<f64>,
+    pub offset: Option<f64>,
+    /// The type used to look up the name of the value, if it's an enum.
+    pub kind: Option<FieldType>,
+    /// Each reference field number and raw value that selects this subfield.
+    pub refs: &'static [(usize, u32)],
+    pub components: &'static [Component],
+}
+
+const SUBFIELD: SubField = SubField {
+    name: "",
+    units: "",
+    scale: None,
+    offset: None,
+    kind: None,
+    refs: &[],
+    components: &[],
+};
+
+/// The subfields of a field of the message with the given global message number.
+pub fn subfields(global_message_number: u16, field_num: usize) -> &'static [SubField] {
+    SUBFIELDS
+        .iter()
+        .find(|(m, f, _)| *m == global_message_number && *f == field_num)
+        .map(|(_, _, s)| *s)
+        .unwrap_or(&[])
+}
+
+const SUBFIELDS: &[(u16, usize, &[SubField])] = &[
+    // file_id: product
+    (
+        0,
+        2,
+        &[
+            SubField {
+                name: "garmin_product",
+                refs: &[(1, 1), (1, 13), (1, 15), (1, 89)],
+                kind: Some(FieldType::GarminProduct),
+                ..SUBFIELD
+            },
+            SubField {
+                name: "favero_product",
+                refs: &[(1, 263)],
+                kind: Some(FieldType::FaveroProduct),
+                ..SUBFIELD
+            },
+        ],
+    ),
+    // event: data
+    (
+        21,
+        3,
+        &[
+            SubField {
+                name: "timer_trigger",
+                refs: &[(0, 0)],
+                kind: Some(FieldType::TimerTrigger),
+                ..SUBFIELD
+            },
+            SubField {
+                name: "course_point_index",
+                refs: &[(0, 10)],
+                ..SUBFIELD
+            },
+            SubField {
+                name: "battery_level",
+                units: "V",
+                scale: Some(1000.0),
+                refs: &[(0, 11)],
+                ..SUBFIELD
+            },
+            SubField {
+                name: "virtual_partner_speed",
+                units: "m/s",
+                scale: Some(1000.0),
+                refs: &[(0, 12)],
+                ..SUBFIELD
+            },
+            SubField {
+                name: "hr_high_alert",
+                units: "bpm",
+                refs: &[(0, 13)],
+                ..SUBFIELD
+            },
+            SubField {
+                name: "hr_low_alert",
+                units: "bpm",
+                refs: &[(0, 14)],
+                ..SUBFIELD
+            },
+            SubField {
+                name: "speed_high_alert",
+                units: "m/s",
+                scale: Some(1000.0),
+                refs: &[(0, 15)],
+                ..SUBFIELD
+            },
+            SubField {
+                name: "speed_low_alert",
+                units: "m/s",
+                scale: Some(1000.0),
+                refs: &[(0, 16)],
+                ..SUBFIELD
+            },
+            SubField {
+                name: "cad_high_alert",
+                units: "rpm",
+                refs: &[(0, 17)],
+                ..SUBFIELD
+            },
+            SubField {
+                name: "cad_low_alert",
+                units: "rpm",
+                refs: &[(0, 18)],
+                ..SUBFIELD
+            },
+            SubField {
+                name: "power_high_alert",
+                units: "watts",
+                refs: &[(0, 19)],
+                ..SUBFIELD
+            },
+            SubField {
+                name: "power_low_alert",
+                units: "watts",
+                refs: &[(0, 20)],
+                ..SUBFIELD
+            },
+            SubField {
+                name: "time_duration_alert",
+                units: "s",
+                scale: Some(1000.0),
+                refs: &[(0, 23)],
+                ..SUBFIELD
+            },
+            SubField {
+                name: "distance_duration_alert",
+                units: "m",
+                scale: Some(100.0),
+                refs: &[(0, 24)],
+                ..SUBFIELD
+            },
+            SubField {
+                name: "calorie_duration_alert",
+                units: "calories",
+                refs: &[(0, 25)],
+                ..SUBFIELD
+            },
+            SubField {
+                name: "fitness_equipment_state",
+                refs: &[(0, 27)],
+                ..SUBFIELD
+            },
+            SubField {
+                name: "sport_point",
+                refs: &[(0, 33)],
+                components: &[c(7, 16, 1.0, 0.0, 4, false), c(8, 16, 1.0, 0.0, 4, false)],
+                ..SUBFIELD
+            },
+            SubField {
+                name: "gear_change_data",
+                refs: &[(0, 42), (0, 43)],
+                components: &[
+                    c(11, 8, 1.0, 0.0, 10, false),
+                    c(12, 8, 1.0, 0.0, 10, false),
+                    c(9, 8, 1.0, 0.0, 10, false),
+                    c(10, 8, 1.0, 0.0, 10, false),
+                ],
+                ..SUBFIELD
+            },
+            SubField {
+                name: "rider_position",
+                refs: &[(0, 44)],
+                ..SUBFIELD
+            },
+            SubField {
+                name: "comm_timeout",
+                refs: &[(0, 47)],
+                ..SUBFIELD
+            },
+            SubField {
+                name: "radar_threat_alert",
+                refs: &[(0, 75)],
+                components: &[c(21, 8, 1.0, 0.0, 0, false), c(22, 8, 1.0, 0.0, 2, false)],
+                ..SUBFIELD
+            },
+        ],
+    ),
+    // device_info: device_type
+    (
+        23,
+        1,
+        &[
+            SubField {
+                name: "antplus_device_type",
+                refs: &[(25, 1)],
+                kind: Some(FieldType::AntplusDeviceType),
+                ..SUBFIELD
+            },
+            SubField {
+                name: "ant_device_type",
+                refs: &[(25, 0)],
+                ..SUBFIELD
+            },
+        ],
+    ),
+    // device_info: product
+    (
+        23,
+        4,
+        &[
+            SubField {
+                name: "garmin_product",
+                refs: &[(2, 1), (2, 13), (2, 15), (2, 89)],
+                kind: Some(FieldType::GarminProduct),
+                ..SUBFIELD
+            },
+            SubField {
+                name: "favero_product",
+                refs: &[(2, 263)],
+                kind: Some(FieldType::FaveroProduct),
+                ..SUBFIELD
+            },
+        ],
+    ),
+    // workout_step: duration_value
+    (
+        27,
+        2,
+        &[
+            SubField {
+                name: "duration_time",
+                units: "s",
+                scale: Some(1000.0),
+                refs: &[(1, 0), (1, 28)],
+                ..SUBFIELD
+            },
+            SubField {
+                name: "duration_distance",
+                units: "m",
+                scale: Some(100.0),
+                refs: &[(1, 1)],
+                ..SUBFIELD
+            },
+            SubField {
+                name: "duration_hr",
+                units: "% or bpm",
+                refs: &[(1, 2), (1, 3)],
+                ..SUBFIELD
+            },
+            SubField {
+                name: "duration_calories",
+                units: "calories",
+                refs: &[(1, 4)],
+                ..SUBFIELD
+            },
+            SubField {
+                name: "duration_step",
+                refs: &[
+                    (1, 6),
+                    (1, 7),
+                    (1, 8),
+                    (1, 9),
+                    (1, 10),
+                    (1, 11),
+                    (1, 12),
+                    (1, 13),
+                ],
+                ..SUBFIELD
+            },
+            SubField {
+                name: "duration_power",
+                units: "% or watts",
+                refs: &[(1, 14), (1, 15)],
+                ..SUBFIELD
+            },
+            SubField {
+                name: "duration_reps",
+                refs: &[(1, 29)],
+                ..SUBFIELD
+            },
+        ],
+    ),
+];
+
+//////////
+//// Messages
+//////////
+
+/// Fields with the same meaning in every message that has them.
+const COMMON: &[FieldInfo] = &[f(253, "timestamp", "s"), f(254, "message_index", "")];
+
+const FILE_ID: &[FieldInfo] = &[
+    f(0, "type", ""),
+    f(1, "manufacturer", ""),
+    f(2, "product", ""),
+    f(3, "serial_number", ""),
+    f(4, "time_created", ""),
+    f(5, "number", ""),
+    f(8, "product_name", ""),
+];
+
+const CAPABILITIES: &[FieldInfo] = &[
+    f(0, "languages", ""),
+    f(1, "sports", ""),
+    f(21, "workouts_supported", ""),
+    f(23, "connectivity_supported", ""),
+];
+
+const DEVICE_SETTINGS: &[FieldInfo] = &[
+    f(0, "active_time_zone", ""),
+    f(1, "utc_offset", ""),
+    f(2, "time_offset", "s"),
+    f(4, "time_mode", ""),
+    s(5, "time_zone_offset", "hr", 4.0),
+    f(12, "backlight_mode", ""),
+    f(36, "activity_tracker_enabled", ""),
+    f(39, "clock_time", ""),
+    f(40, "pages_enabled", ""),
+    f(46, "move_alert_enabled", ""),
+    f(47, "date_mode", ""),
+    f(55, "display_orientation", ""),
+    f(56, "mounting_side", ""),
+    f(57, "default_page", ""),
+    f(58, "autosync_min_steps", "steps"),
+    f(59, "autosync_min_time", "minutes"),
+    f(80, "lactate_threshold_autodetect_enabled", ""),
+    f(86, "ble_auto_upload_enabled", ""),
+    f(89, "auto_sync_frequency", ""),
+    f(90, "auto_activity_detect", ""),
+    f(94, "number_of_screens", ""),
+    f(95, "smart_notification_display_orientation", ""),
+    f(134, "tap_interface", ""),
+    f(174, "tap_sensitivity", ""),
+];
+
+const USER_PROFILE: &[FieldInfo] = &[
+    f(0, "friendly_name", ""),
+    f(1, "gender", ""),
+    f(2, "age", "years"),
+    s(3, "height", "m", 100.0),
+    s(4, "weight", "kg", 10.0),
+    f(5, "language", ""),
+    f(6, "elev_setting", ""),
+    f(7, "weight_setting", ""),
+    f(8, "resting_heart_rate", "bpm"),
+    f(9, "default_max_running_heart_rate", "bpm"),
+    f(10, "default_max_biking_heart_rate", "bpm"),
+    f(11, "default_max_heart_rate", "bpm"),
+    f(12, "hr_setting", ""),
+    f(13, "speed_setting", ""),
+    f(14, "dist_setting", ""),
+    f(16, "power_setting", ""),
+    s(17, "activity_class", "", 2.0),
+    f(18, "position_setting", ""),
+    f(21, "temperature_setting", ""),
+    f(22, "local_id", ""),
+    f(23, "global_id", ""),
+    f(28, "wake_time", ""),
+    f(29, "sleep_time", ""),
+    f(30, "height_setting", ""),
+    s(31, "user_running_step_length", "m", 1000.0),
+    s(32, "user_walking_step_length", "m", 1000.0),
+    f(47, "depth_setting", ""),
+    f(49, "dive_count", ""),
+];
+
+const HRM_PROFILE: &[FieldInfo] = &[
+    f(0, "enabled", ""),
+    f(1, "hrm_ant_id", ""),
+    f(2, "log_hrv", ""),
+    f(3, "hrm_ant_id_trans_type", ""),
+];
+
+const SDM_PROFILE: &[FieldInfo] = &[
+    f(0, "enabled", ""),
+    f(1, "sdm_ant_id", ""),
+    s(2, "sdm_cal_factor", "%", 10.0),
+    s(3, "odometer", "m", 100.0),
+    f(4, "speed_source", ""),
+    f(5, "sdm_ant_id_trans_type", ""),
+    f(7, "odometer_rollover", ""),
+];
+
+const BIKE_PROFILE: &[FieldInfo] = &[
+    f(0, "name", ""),
+    f(1, "sport", ""),
+    f(2, "sub_sport", ""),
+    s(3, "odometer", "m", 100.0),
+    f(4, "bike_spd_ant_id", ""),
+    f(5, "bike_cad_ant_id", ""),
+    f(6, "bike_spdcad_ant_id", ""),
+    f(7, "bike_power_ant_id", ""),
+    s(8, "custom_wheelsize", "m", 1000.0),
+    s(9, "auto_wheelsize", "m", 1000.0),
+    s(10, "bike_weight", "kg", 10.0),
+    s(11, "power_cal_factor", "%", 10.0),
+    f(12, "auto_wheel_cal", ""),
+    f(13, "auto_power_zero", ""),
+    f(14, "id", ""),
+    f(15, "spd_enabled", ""),
+    f(16, "cad_enabled", ""),
+    f(17, "spdcad_enabled", ""),
+    f(18, "power_enabled", ""),
+    so(19, "crank_length", "mm", 2.0, -110.0),
+    f(20, "enabled", ""),
+    f(21, "bike_spd_ant_id_trans_type", ""),
+    f(22, "bike_cad_ant_id_trans_type", ""),
+    f(23, "bike_spdcad_ant_id_trans_type", ""),
+    f(24, "bike_power_ant_id_trans_type", ""),
+    f(37, "odometer_rollover", ""),
+    f(38, "front_gear_num", ""),
+    f(39, "front_gear", ""),
+    f(40, "rear_gear_num", ""),
+    f(41, "rear_gear", ""),
+    f(44, "shimano_di2_enabled", ""),
+];
+
+const ZONES_TARGET: &[FieldInfo] = &[
+    f(1, "max_heart_rate", "bpm"),
+    f(2, "threshold_heart_rate", "bpm"),
+    f(3, "functional_threshold_power", "watts"),
+    f(5, "hr_calc_type", ""),
+    f(7, "pwr_calc_type", ""),
+];
+
+const HR_ZONE: &[FieldInfo] = &[f(1, "high_bpm", "bpm"), f(2, "name", "")];
+
+const POWER_ZONE: &[FieldInfo] = &[f(1, "high_value", "watts"), f(2, "name", "")];
+
+const MET_ZONE: &[FieldInfo] = &[
+    f(1, "high_bpm", "bpm"),
+    s(2, "calories", "kcal / min", 10.0),
+    s(3, "fat_calories", "kcal / min", 10.0),
+];
+
+const SPORT: &[FieldInfo] = &[f(0, "sport", ""), f(1, "sub_sport", ""), f(3, "name", "")];
+
+const TRAINING_SETTINGS: &[FieldInfo] = &[
+    s(31, "target_distance", "m", 100.0),
+    s(32, "target_speed", "m/s", 1000.0),
+    f(33, "target_time", "s"),
+    s(153, "precise_target_speed", "m/s", 1000000.0),
+];
+
+const GOAL: &[FieldInfo] = &[
+    f(0, "sport", ""),
+    f(1, "sub_sport", ""),
+    f(2, "start_date", ""),
+    f(3, "end_date", ""),
+    f(4, "type", ""),
+    f(5, "value", ""),
+    f(6, "repeat", ""),
+    f(7, "target_value", ""),
+    f(8, "recurrence", ""),
+    f(9, "recurrence_value", ""),
+    f(10, "enabled", ""),
+    f(11, "source", ""),
+];
+
+const SESSION: &[FieldInfo] = &[
+    f(0, "event", ""),
+    f(1, "event_type", ""),
+    f(2, "start_time", ""),
+    f(3, "start_position_lat", "semicircles"),
+    f(4, "start_position_long", "semicircles"),
+    f(5, "sport", ""),
+    f(6, "sub_sport", ""),
+    s(7, "total_elapsed_time", "s", 1000.0),
+    s(8, "total_timer_time", "s", 1000.0),
+    s(9, "total_distance", "m", 100.0),
+    f(10, "total_cycles", "cycles"),
+    f(11, "total_calories", "kcal"),
+    f(13, "total_fat_calories", "kcal"),
+    s(14, "avg_speed", "m/s", 1000.0),
+    s(15, "max_speed", "m/s", 1000.0),
+    f(16, "avg_heart_rate", "bpm"),
+    f(17, "max_heart_rate", "bpm"),
+    f(18, "avg_cadence", "rpm"),
+    f(19, "max_cadence", "rpm"),
+    f(20, "avg_power", "watts"),
+    f(21, "max_power", "watts"),
+    f(22, "total_ascent", "m"),
+    f(23, "total_descent", "m"),
+    s(24, "total_training_effect", "", 10.0),
+    f(25, "first_lap_index", ""),
+    f(26, "num_laps", ""),
+    f(27, "event_group", ""),
+    f(28, "trigger", ""),
+    f(29, "nec_lat", "semicircles"),
+    f(30, "nec_long", "semicircles"),
+    f(31, "swc_lat", "semicircles"),
+    f(32, "swc_long", "semicircles"),
+    f(33, "num_lengths", "lengths"),
+    f(34, "normalized_power", "watts"),
+    s(35, "training_stress_score", "tss", 10.0),
+    s(36, "intensity_factor", "if", 1000.0),
+    f(37, "left_right_balance", ""),
+    f(38, "end_position_lat", "semicircles"),
+    f(39, "end_position_long", "semicircles"),
+    s(41, "avg_stroke_count", "strokes/lap", 10.0),
+    s(42, "avg_stroke_distance", "m", 100.0),
+    f(43, "swim_stroke", "swim_stroke"),
+    s(44, "pool_length", "m", 100.0),
+    f(45, "threshold_power", "watts"),
+    f(46, "pool_length_unit", ""),
+    f(47, "num_active_lengths", "lengths"),
+    f(48, "total_work", "J"),
+    so(49, "avg_altitude", "m", 5.0, 500.0),
+    so(50, "max_altitude", "m", 5.0, 500.0),
+    f(51, "gps_accuracy", "m"),
+    s(52, "avg_grade", "%", 100.0),
+    s(53, "avg_pos_grade", "%", 100.0),
+    s(54, "avg_neg_grade", "%", 100.0),
+    s(55, "max_pos_grade", "%", 100.0),
+    s(56, "max_neg_grade", "%", 100.0),
+    f(57, "avg_temperature", "C"),
+    f(58, "max_temperature", "C"),
+    s(59, "total_moving_time", "s", 1000.0),
+    s(60, "avg_pos_vertical_speed", "m/s", 1000.0),
+    s(61, "avg_neg_vertical_speed", "m/s", 1000.0),
+    s(62, "max_pos_vertical_speed", "m/s", 1000.0),
+    s(63, "max_neg_vertical_speed", "m/s", 1000.0),
+    f(64, "min_heart_rate", "bpm"),
+    s(65, "time_in_hr_zone", "s", 1000.0),
+    s(66, "time_in_speed_zone", "s", 1000.0),
+    s(67, "time_in_cadence_zone", "s", 1000.0),
+    s(68, "time_in_power_zone", "s", 1000.0),
+    s(69, "avg_lap_time", "s", 1000.0),
+    f(70, "best_lap_index", ""),
+    so(71, "min_altitude", "m", 5.0, 500.0),
+    f(82, "player_score", ""),
+    f(83, "opponent_score", ""),
+    f(84, "opponent_name", ""),
+    f(85, "stroke_count", "counts"),
+    f(86, "zone_count", "counts"),
+    s(87, "max_ball_speed", "m/s", 100.0),
+    s(88, "avg_ball_speed", "m/s", 100.0),
+    s(89, "avg_vertical_oscillation", "mm", 10.0),
+    s(90, "avg_stance_time_percent", "percent", 100.0),
+    s(91, "avg_stance_time", "ms", 10.0),
+    s(92, "avg_fractional_cadence", "rpm", 128.0),
+    s(93, "max_fractional_cadence", "rpm", 128.0),
+    s(94, "total_fractional_cycles", "cycles", 128.0),
+    s(95, "avg_total_hemoglobin_conc", "g/dL", 100.0),
+    s(96, "min_total_hemoglobin_conc", "g/dL", 100.0),
+    s(97, "max_total_hemoglobin_conc", "g/dL", 100.0),
+    s(98, "avg_saturated_hemoglobin_percent", "%", 10.0),
+    s(99, "min_saturated_hemoglobin_percent", "%", 10.0),
+    s(100, "max_saturated_hemoglobin_percent", "%", 10.0),
+    s(101, "avg_left_torque_effectiveness", "percent", 2.0),
+    s(102, "avg_right_torque_effectiveness", "percent", 2.0),
+    s(103, "avg_left_pedal_smoothness", "percent", 2.0),
+    s(104, "avg_right_pedal_smoothness", "percent", 2.0),
+    s(105, "avg_combined_pedal_smoothness", "percent", 2.0),
+    f(110, "sport_profile_name", ""),
+    f(111, "sport_index", ""),
+    s(112, "time_standing", "s", 1000.0),
+    f(113, "stand_count", ""),
+    f(114, "avg_left_pco", "mm"),
+    f(115, "avg_right_pco", "mm"),
+    s(116, "avg_left_power_phase", "degrees", 0.7111111),
+    s(117, "avg_left_power_phase_peak", "degrees", 0.7111111),
+    s(118, "avg_right_power_phase", "degrees", 0.7111111),
+    s(119, "avg_right_power_phase_peak", "degrees", 0.7111111),
+    f(120, "avg_power_position", "watts"),
+    f(121, "max_power_position", "watts"),
+    f(122, "avg_cadence_position", "rpm"),
+    f(123, "max_cadence_position", "rpm"),
+    s(124, "enhanced_avg_speed", "m/s", 1000.0),
+    s(125, "enhanced_max_speed", "m/s", 1000.0),
+    so(126, "enhanced_avg_altitude", "m", 5.0, 500.0),
+    so(127, "enhanced_min_altitude", "m", 5.0, 500.0),
+    so(128, "enhanced_max_altitude", "m", 5.0, 500.0),
+    f(129, "avg_lev_motor_power", "watts"),
+    f(130, "max_lev_motor_power", "watts"),
+    s(131, "lev_battery_consumption", "percent", 2.0),
+    s(132, "avg_vertical_ratio", "percent", 100.0),
+    s(133, "avg_stance_time_balance", "percent", 100.0),
+    s(134, "avg_step_length", "mm", 10.0),
+    s(137, "total_anaerobic_training_effect", "", 10.0),
+    s(139, "avg_vam", "m/s", 1000.0),
+    s(140, "avg_depth", "m", 1000.0),
+    s(141, "max_depth", "m", 1000.0),
+    f(142, "surface_interval", "s"),
+    f(143, "start_cns", "percent"),
+    f(144, "end_cns", "percent"),
+    f(145, "start_n2", "percent"),
+    f(146, "end_n2", "percent"),
+    f(147, "avg_respiration_rate", ""),
+    f(148, "max_respiration_rate", ""),
+    f(149, "min_respiration_rate", ""),
+    f(150, "min_temperature", "C"),
+    f(155, "o2_toxicity", "OTUs"),
+    f(156, "dive_number", ""),
+    s(168, "training_load_peak", "", 65536.0),
+    s(169, "enhanced_avg_respiration_rate", "Breaths/min", 100.0),
+    s(170, "enhanced_max_respiration_rate", "Breaths/min", 100.0),
+    s(180, "enhanced_min_respiration_rate", "", 100.0),
+    f(181, "total_grit", "kGrit"),
+    f(182, "total_flow", "Flow"),
+    f(183, "jump_count", ""),
+    f(186, "avg_grit", "kGrit"),
+    f(187, "avg_flow", "Flow"),
+    f(192, "workout_feel", ""),
+    f(193, "workout_rpe", ""),
+    f(194, "avg_spo2", "percent"),
+    f(195, "avg_stress", "percent"),
+    s(199, "total_fractional_ascent", "m", 100.0),
+    s(200, "total_fractional_descent", "m", 100.0),
+    s(208, "avg_core_temperature", "C", 100.0),
+    s(209, "min_core_temperature", "C", 100.0),
+    s(210, "max_core_temperature", "C", 100.0),
+];
+
+const LAP: &[FieldInfo] = &[
+    f(0, "event", ""),
+    f(1, "event_type", ""),
+    f(2, "start_time", ""),
+    f(3, "start_position_lat", "semicircles"),
+    f(4, "start_position_long", "semicircles"),
+    f(5, "end_position_lat", "semicircles"),
+    f(6, "end_position_long", "semicircles"),
+    s(7, "total_elapsed_time", "s", 1000.0),
+    s(8, "total_timer_time", "s", 1000.0),
+    s(9, "total_distance", "m", 100.0),
+    f(10, "total_cycles", "cycles"),
+    f(11, "total_calories", "kcal"),
+    f(12, "total_fat_calories", "kcal"),
+    s(13, "avg_speed", "m/s", 1000.0),
+    s(14, "max_speed", "m/s", 1000.0),
+    f(15, "avg_heart_rate", "bpm"),
+    f(16, "max_heart_rate", "bpm"),
+    f(17, "avg_cadence", "rpm"),
+    f(18, "max_cadence", "rpm"),
+    f(19, "avg_power", "watts"),
+    f(20, "max_power", "watts"),
+    f(21, "total_ascent", "m"),
+    f(22, "total_descent", "m"),
+    f(23, "intensity", ""),
+    f(24, "lap_trigger", ""),
+    f(25, "sport", ""),
+    f(26, "event_group", ""),
+    f(32, "num_lengths", "lengths"),
+    f(33, "normalized_power", "watts"),
+    f(34, "left_right_balance", ""),
+    f(35, "first_length_index", ""),
+    s(37, "avg_stroke_distance", "m", 100.0),
+    f(38, "swim_stroke", ""),
+    f(39, "sub_sport", ""),
+    f(40, "num_active_lengths", "lengths"),
+    f(41, "total_work", "J"),
+    so(42, "avg_altitude", "m", 5.0, 500.0),
+    so(43, "max_altitude", "m", 5.0, 500.0),
+    f(44, "gps_accuracy", "m"),
+    s(45, "avg_grade", "%", 100.0),
+    s(46, "avg_pos_grade", "%", 100.0),
+    s(47, "avg_neg_grade", "%", 100.0),
+    s(48, "max_pos_grade", "%", 100.0),
+    s(49, "max_neg_grade", "%", 100.0),
+    f(50, "avg_temperature", "C"),
+    f(51, "max_temperature", "C"),
+    s(52, "total_moving_time", "s", 1000.0),
+    s(53, "avg_pos_vertical_speed", "m/s", 1000.0),
+    s(54, "avg_neg_vertical_speed", "m/s", 1000.0),
+    s(55, "max_pos_vertical_speed", "m/s", 1000.0),
+    s(56, "max_neg_vertical_speed", "m/s", 1000.0),
+    s(57, "time_in_hr_zone", "s", 1000.0),
+    s(58, "time_in_speed_zone", "s", 1000.0),
+    s(59, "time_in_cadence_zone", "s", 1000.0),
+    s(60, "time_in_power_zone", "s", 1000.0),
+    f(61, "repetition_num", ""),
+    so(62, "min_altitude", "m", 5.0, 500.0),
+    f(63, "min_heart_rate", "bpm"),
+    f(71, "wkt_step_index", ""),
+    f(74, "opponent_score", ""),
+    f(75, "stroke_count", "counts"),
+    f(76, "zone_count", "counts"),
+    s(77, "avg_vertical_oscillation", "mm", 10.0),
+    s(78, "avg_stance_time_percent", "percent", 100.0),
+    s(79, "avg_stance_time", "ms", 10.0),
+    s(80, "avg_fractional_cadence", "rpm", 128.0),
+    s(81, "max_fractional_cadence", "rpm", 128.0),
+    s(82, "total_fractional_cycles", "cycles", 128.0),
+    f(83, "player_score", ""),
+    s(84, "avg_total_hemoglobin_conc", "g/dL", 100.0),
+    s(85, "min_total_hemoglobin_conc", "g/dL", 100.0),
+    s(86, "max_total_hemoglobin_conc", "g/dL", 100.0),
+    s(87, "avg_saturated_hemoglobin_percent", "%", 10.0),
+    s(88, "min_saturated_hemoglobin_percent", "%", 10.0),
+    s(89, "max_saturated_hemoglobin_percent", "%", 10.0),
+    s(91, "avg_left_torque_effectiveness", "percent", 2.0),
+    s(92, "avg_right_torque_effectiveness", "percent", 2.0),
+    s(93, "avg_left_pedal_smoothness", "percent", 2.0),
+    s(94, "avg_right_pedal_smoothness", "percent", 2.0),
+    s(95, "avg_combined_pedal_smoothness", "percent", 2.0),
+    s(98, "time_standing", "s", 1000.0),
+    f(99, "stand_count", ""),
+    f(100, "avg_left_pco", "mm"),
+    f(101, "avg_right_pco", "mm"),
+    s(102, "avg_left_power_phase", "degrees", 0.7111111),
+    s(103, "avg_left_power_phase_peak", "degrees", 0.7111111),
+    s(104, "avg_right_power_phase", "degrees", 0.7111111),
+    s(105, "avg_right_power_phase_peak", "degrees", 0.7111111),
+    f(106, "avg_power_position", "watts"),
+    f(107, "max_power_position", "watts"),
+    f(108, "avg_cadence_position", "rpm"),
+    f(109, "max_cadence_position", "rpm"),
+    s(110, "enhanced_avg_speed", "m/s", 1000.0),
+    s(111, "enhanced_max_speed", "m/s", 1000.0),
+    so(112, "enhanced_avg_altitude", "m", 5.0, 500.0),
+    so(113, "enhanced_min_altitude", "m", 5.0, 500.0),
+    so(114, "enhanced_max_altitude", "m", 5.0, 500.0),
+    f(115, "avg_lev_motor_power", "watts"),
+    f(116, "max_lev_motor_power", "watts"),
+    s(117, "lev_battery_consumption", "percent", 2.0),
+    s(118, "avg_vertical_ratio", "percent", 100.0),
+    s(119, "avg_stance_time_balance", "percent", 100.0),
+    s(120, "avg_step_length", "mm", 10.0),
+    s(121, "avg_vam", "m/s", 1000.0),
+    s(122, "avg_depth", "m", 1000.0),
+    s(123, "max_depth", "m", 1000.0),
+    f(124, "min_temperature", "C"),
+    s(136, "enhanced_avg_respiration_rate", "Breaths/min", 100.0),
+    s(137, "enhanced_max_respiration_rate", "Breaths/min", 100.0),
+    f(147, "avg_respiration_rate", ""),
+    f(148, "max_respiration_rate", ""),
+    f(149, "total_grit", "kGrit"),
+    f(150, "total_flow", "Flow"),
+    f(151, "jump_count", ""),
+    f(153, "avg_grit", "kGrit"),
+    f(154, "avg_flow", "Flow"),
+    s(156, "total_fractional_ascent", "m", 100.0),
+    s(157, "total_fractional_descent", "m", 100.0),
+    s(158, "avg_core_temperature", "C", 100.0),
+    s(159, "min_core_temperature", "C", 100.0),
+    s(160, "max_core_temperature", "C", 100.0),
+];
+
+const RECORD: &[FieldInfo] = &[
+    f(0, "position_lat", "semicircles"),
+    f(1, "position_long", "semicircles"),
+    so(2, "altitude", "m", 5.0, 500.0),
+    f(3, "heart_rate", "bpm"),
+    f(4, "cadence", "rpm"),
+    s(5, "distance", "m", 100.0),
+    s(6, "speed", "m/s", 1000.0),
+    f(7, "power", "watts"),
+    f(8, "compressed_speed_distance", ""),
+    s(9, "grade", "%", 100.0),
+    f(10, "resistance", ""),
+    s(11, "time_from_course", "s", 1000.0),
+    s(12, "cycle_length", "m", 100.0),
+    f(13, "temperature", "C"),
+    s(17, "speed_1s", "m/s", 16.0),
+    f(18, "cycles", "cycles"),
+    f(19, "total_cycles", "cycles"),
+    f(28, "compressed_accumulated_power", "watts"),
+    f(29, "accumulated_power", "watts"),
+    f(30, "left_right_balance", ""),
+    f(31, "gps_accuracy", "m"),
+    s(32, "vertical_speed", "m/s", 1000.0),
+    f(33, "calories", "kcal"),
+    s(39, "vertical_oscillation", "mm", 10.0),
+    s(40, "stance_time_percent", "percent", 100.0),
+    s(41, "stance_time", "ms", 10.0),
+    f(42, "activity_type", ""),
+    s(43, "left_torque_effectiveness", "percent", 2.0),
+    s(44, "right_torque_effectiveness", "percent", 2.0),
+    s(45, "left_pedal_smoothness", "percent", 2.0),
+    s(46, "right_pedal_smoothness", "percent", 2.0),
+    s(47, "combined_pedal_smoothness", "percent", 2.0),
+    s(48, "time128", "s", 128.0),
+    f(49, "stroke_type", ""),
+    f(50, "zone", ""),
+    s(51, "ball_speed", "m/s", 100.0),
+    s(52, "cadence256", "rpm", 256.0),
+    s(53, "fractional_cadence", "rpm", 128.0),
+    s(54, "total_hemoglobin_conc", "g/dL", 100.0),
+    s(55, "total_hemoglobin_conc_min", "g/dL", 100.0),
+    s(56, "total_hemoglobin_conc_max", "g/dL", 100.0),
+    s(57, "saturated_hemoglobin_percent", "%", 10.0),
+    s(58, "saturated_hemoglobin_percent_min", "%", 10.0),
+    s(59, "saturated_hemoglobin_percent_max", "%", 10.0),
+    f(62, "device_index", ""),
+    f(67, "left_pco", "mm"),
+    f(68, "right_pco", "mm"),
+    s(69, "left_power_phase", "degrees", 0.7111111),
+    s(70, "left_power_phase_peak", "degrees", 0.7111111),
+    s(71, "right_power_phase", "degrees", 0.7111111),
+    s(72, "right_power_phase_peak", "degrees", 0.7111111),
+    s(73, "enhanced_speed", "m/s", 1000.0),
+    so(78, "enhanced_altitude", "m", 5.0, 500.0),
+    s(81, "battery_soc", "percent", 2.0),
+    f(82, "motor_power", "watts"),
+    s(83, "vertical_ratio", "percent", 100.0),
+    s(84, "stance_time_balance", "percent", 100.0),
+    s(85, "step_length", "mm", 10.0),
+    s(87, "cycle_length16", "m", 100.0),
+    f(91, "absolute_pressure", "Pa"),
+    s(92, "depth", "m", 1000.0),
+    s(93, "next_stop_depth", "m", 1000.0),
+    f(94, "next_stop_time", "s"),
+    f(95, "time_to_surface", "s"),
+    f(96, "ndl_time", "s"),
+    f(97, "cns_load", "percent"),
+    f(98, "n2_load", "percent"),
+    f(99, "respiration_rate", "breaths/min"),
+    s(108, "enhanced_respiration_rate", "Breaths/min", 100.0),
+    f(114, "grit", ""),
+    f(115, "flow", ""),
+    s(116, "current_stress", "", 100.0),
+    f(117, "ebike_travel_range", "km"),
+    f(118, "ebike_battery_level", "percent"),
+    f(119, "ebike_assist_mode", ""),
+    f(120, "ebike_assist_level_percent", "percent"),
+    f(123, "air_time_remaining", "s"),
+    s(124, "pressure_sac", "bar/min", 100.0),
+    s(125, "volume_sac", "L/min", 100.0),
+    s(126, "rmv", "L/min", 100.0),
+    s(127, "ascent_rate", "m/s", 1000.0),
+    s(129, "po2", "percent", 100.0),
+    s(139, "core_temperature", "C", 100.0),
+];
+
+const EVENT: &[FieldInfo] = &[
+    f(0, "event", ""),
+    f(1, "event_type", ""),
+    f(2, "data16", ""),
+    f(3, "data", ""),
+    f(4, "event_group", ""),
+    f(7, "score", ""),
+    f(8, "opponent_score", ""),
+    f(9, "front_gear_num", ""),
+    f(10, "front_gear", ""),
+    f(11, "rear_gear_num", ""),
+    f(12, "rear_gear", ""),
+    f(13, "device_index", ""),
+    f(21, "radar_threat_level_max", ""),
+    f(22, "radar_threat_count", ""),
+    s(23, "radar_threat_avg_approach_speed", "m/s", 10.0),
+    s(24, "radar_threat_max_approach_speed", "m/s", 10.0),
+];
+
+const DEVICE_INFO: &[FieldInfo] = &[
+    f(0, "device_index", ""),
+    f(1, "device_type", ""),
+    f(2, "manufacturer", ""),
+    f(3, "serial_number", ""),
+    f(4, "product", ""),
+    s(5, "software_version", "", 100.0),
+    f(6, "hardware_version", ""),
+    f(7, "cum_operating_time", "s"),
+    s(10, "battery_voltage", "V", 256.0),
+    f(11, "battery_status", ""),
+    f(18, "sensor_position", ""),
+    f(19, "descriptor", ""),
+    f(20, "ant_transmission_type", ""),
+    f(21, "ant_device_number", ""),
+    f(22, "ant_network", ""),
+    f(25, "source_type", ""),
+    f(27, "product_name", ""),
+    f(32, "battery_level", "%"),
+];
+
+const WORKOUT: &[FieldInfo] = &[
+    f(4, "sport", ""),
+    f(5, "capabilities", ""),
+    f(6, "num_valid_steps", ""),
+    f(8, "wkt_name", ""),
+    f(11, "sub_sport", ""),
+    s(14, "pool_length", "m", 100.0),
+    f(15, "pool_length_unit", ""),
+];
+
+const WORKOUT_STEP: &[FieldInfo] = &[
+    f(0, "wkt_step_name", ""),
+    f(1, "duration_type", ""),
+    f(2, "duration_value", ""),
+    f(3, "target_type", ""),
+    f(4, "target_value", ""),
+    f(5, "custom_target_value_low", ""),
+    f(6, "custom_target_value_high", ""),
+    f(7, "intensity", ""),
+    f(8, "notes", ""),
+    f(9, "equipment", ""),
+    f(10, "exercise_category", ""),
+    f(11, "exercise_name", ""),
+    s(12, "exercise_weight", "kg", 100.0),
+    f(13, "weight_display_unit", ""),
+    f(19, "secondary_target_type", ""),
+    f(20, "secondary_target_value", ""),
+    f(21, "secondary_custom_target_value_low", ""),
+    f(22, "secondary_custom_target_value_high", ""),
+];
+
+const SCHEDULE: &[FieldInfo] = &[
+    f(0, "manufacturer", ""),
+    f(1, "product", ""),
+    f(2, "serial_number", ""),
+    f(3, "time_created", ""),
+    f(4, "completed", ""),
+    f(5, "type", ""),
+    f(6, "scheduled_time", ""),
+];
+
+const WEIGHT_SCALE: &[FieldInfo] = &[
+    s(0, "weight", "kg", 100.0),
+    s(1, "percent_fat", "%", 100.0),
+    s(2, "percent_hydration", "%", 100.0),
+    s(3, "visceral_fat_mass", "kg", 100.0),
+    s(4, "bone_mass", "kg", 100.0),
+    s(5, "muscle_mass", "kg", 100.0),
+    s(7, "basal_met", "kcal/day", 4.0),
+    f(8, "physique_rating", ""),
+    s(9, "active_met", "kcal/day", 4.0),
+    f(10, "metabolic_age", "years"),
+    f(11, "visceral_fat_rating", ""),
+    f(12, "user_profile_index", ""),
+    s(13, "bmi", "kg/m^2", 10.0),
+];
+
+const COURSE: &[FieldInfo] = &[
+    f(4, "sport", ""),
+    f(5, "name", ""),
+    f(6, "capabilities", ""),
+    f(7, "sub_sport", ""),
+];
+
+const COURSE_POINT: &[FieldInfo] = &[
+    f(1, "timestamp", ""),
+    f(2, "position_lat", "semicircles"),
+    f(3, "position_long", "semicircles"),
+    s(4, "distance", "m", 100.0),
+    f(5, "type", ""),
+    f(6, "name", ""),
+    f(8, "favorite", ""),
+];
+
+const TOTALS: &[FieldInfo] = &[
+    f(0, "timer_time", "s"),
+    f(1, "distance", "m"),
+    f(2, "calories", "kcal"),
+    f(3, "sport", ""),
+    f(4, "elapsed_time", "s"),
+    f(5, "sessions", ""),
+    f(6, "active_time", "s"),
+    f(9, "sport_index", ""),
+];
+
+const ACTIVITY: &[FieldInfo] = &[
+    s(0, "total_timer_time", "s", 1000.0),
+    f(1, "num_sessions", ""),
+    f(2, "type", ""),
+    f(3, "event", ""),
+    f(4, "event_type", ""),
+    f(5, "local_timestamp", ""),
+    f(6, "event_group", ""),
+];
+
+const SOFTWARE: &[FieldInfo] = &[s(3, "version", "", 100.0), f(5, "part_number", "")];
+
+const FILE_CAPABILITIES: &[FieldInfo] = &[
+    f(0, "type", ""),
+    f(1, "flags", ""),
+    f(2, "directory", ""),
+    f(3, "max_count", ""),
+    f(4, "max_size", "bytes"),
+];
+
+const MESG_CAPABILITIES: &[FieldInfo] = &[
+    f(0, "file", ""),
+    f(1, "mesg_num", ""),
+    f(2, "count_type", ""),
+    f(3, "count", ""),
+];
+
+const FIELD_CAPABILITIES: &[FieldInfo] = &[
+    f(0, "file", ""),
+    f(1, "mesg_num", ""),
+    f(2, "field_num", ""),
+    f(3, "count", ""),
+];
+
+const FILE_CREATOR: &[FieldInfo] = &[f(0, "software_version", ""), f(1, "hardware_version", "")];
+
+const BLOOD_PRESSURE: &[FieldInfo] = &[
+    f(0, "systolic_pressure", "mmHg"),
+    f(1, "diastolic_pressure", "mmHg"),
+    f(2, "mean_arterial_pressure", "mmHg"),
+    f(3, "map_3_sample_mean", "mmHg"),
+    f(4, "map_morning_values", "mmHg"),
+    f(5, "map_evening_values", "mmHg"),
+    f(6, "heart_rate", "bpm"),
+    f(7, "heart_rate_type", ""),
+    f(8, "status", ""),
+    f(9, "user_profile_index", ""),
+];
+
+const SPEED_ZONE: &[FieldInfo] = &[s(0, "high_value", "m/s", 1000.0), f(1, "name", "")];
+
+const MONITORING: &[FieldInfo] = &[
+    f(0, "device_index", ""),
+    f(1, "calories", "kcal"),
+    s(2, "distance", "m", 100.0),
+    s(3, "cycles", "cycles", 2.0),
+    s(4, "active_time", "s", 1000.0),
+    f(5, "activity_type", ""),
+    f(6, "activity_subtype", ""),
+    f(7, "activity_level", ""),
+    f(8, "distance_16", "100 * m"),
+    f(9, "cycles_16", "2 * cycles (steps)"),
+    f(10, "active_time_16", "s"),
+    f(11, "local_timestamp", ""),
+    s(12, "temperature", "C", 100.0),
+    s(14, "temperature_min", "C", 100.0),
+    s(15, "temperature_max", "C", 100.0),
+    f(16, "activity_time", "minutes"),
+    f(19, "active_calories", "kcal"),
+    f(24, "current_activity_type_intensity", ""),
+    f(25, "timestamp_min_8", "min"),
+    f(26, "timestamp_16", "s"),
+    f(27, "heart_rate", "bpm"),
+    s(28, "intensity", "", 10.0),
+    f(29, "duration_min", "min"),
+    f(30, "duration", "s"),
+    s(31, "ascent", "m", 1000.0),
+    s(32, "descent", "m", 1000.0),
+    f(33, "moderate_activity_minutes", "minutes"),
+    f(34, "vigorous_activity_minutes", "minutes"),
+];
+
+const TRAINING_FILE: &[FieldInfo] = &[
+    f(0, "type", ""),
+    f(1, "manufacturer", ""),
+    f(2, "product", ""),
+    f(3, "serial_number", ""),
+    f(4, "time_created", ""),
+];
+
+const HRV: &[FieldInfo] = &[s(0, "time", "s", 1000.0)];
+
+const ANT_RX: &[FieldInfo] = &[
+    s(0, "fractional_timestamp", "s", 32768.0),
+    f(1, "mesg_id", ""),
+    f(2, "mesg_data", ""),
+    f(3, "channel_number", ""),
+    f(4, "data", ""),
+];
+
+const ANT_TX: &[FieldInfo] = &[
+    s(0, "fractional_timestamp", "s", 32768.0),
+    f(1, "mesg_id", ""),
+    f(2, "mesg_data", ""),
+    f(3, "channel_number", ""),
+    f(4, "data", ""),
+];
+
+const ANT_CHANNEL_ID: &[FieldInfo] = &[
+    f(0, "channel_number", ""),
+    f(1, "device_type", ""),
+    f(2, "device_number", ""),
+    f(3, "transmission_type", ""),
+    f(4, "device_index", ""),
+];
+
+const LENGTH: &[FieldInfo] = &[
+    f(0, "event", ""),
+    f(1, "event_type", ""),
+    f(2, "start_time", ""),
+    s(3, "total_elapsed_time", "s", 1000.0),
+    s(4, "total_timer_time", "s", 1000.0),
+    f(5, "total_strokes", "strokes"),
+    s(6, "avg_speed", "m/s", 1000.0),
+    f(7, "swim_stroke", "swim_stroke"),
+    f(9, "avg_swimming_cadence", "strokes/min"),
+    f(10, "event_group", ""),
+    f(11, "total_calories", "kcal"),
+    f(12, "length_type", ""),
+    f(18, "player_score", ""),
+    f(19, "opponent_score", ""),
+    f(20, "stroke_count", "counts"),
+    f(21, "zone_count", "counts"),
+];
+
+const MONITORING_INFO: &[FieldInfo] = &[
+    f(0, "local_timestamp", "s"),
+    f(1, "activity_type", ""),
+    s(3, "cycles_to_distance", "m/cycle", 5000.0),
+    s(4, "cycles_to_calories", "kcal/cycle", 5000.0),
+    f(5, "resting_metabolic_rate", "kcal / day"),
+];
+
+const SLAVE_DEVICE: &[FieldInfo] = &[f(0, "manufacturer", ""), f(1, "product", "")];
+
+const CONNECTIVITY: &[FieldInfo] = &[
+    f(0, "bluetooth_enabled", ""),
+    f(1, "bluetooth_le_enabled", ""),
+    f(2, "ant_enabled", ""),
+    f(3, "name", ""),
+    f(4, "live_tracking_enabled", ""),
+    f(5, "weather_conditions_enabled", ""),
+    f(6, "weather_alerts_enabled", ""),
+    f(7, "auto_activity_upload_enabled", ""),
+    f(8, "course_download_enabled", ""),
+    f(9, "workout_download_enabled", ""),
+    f(10, "gps_ephemeris_download_enabled", ""),
+    f(11, "incident_detection_enabled", ""),
+    f(12, "grouptrack_enabled", ""),
+];
+
+const WEATHER_CONDITIONS: &[FieldInfo] = &[
+    f(0, "weather_report", ""),
+    f(1, "temperature", "C"),
+    f(2, "condition", ""),
+    f(3, "wind_direction", "degrees"),
+    s(4, "wind_speed", "m/s", 1000.0),
+    f(5, "precipitation_probability", ""),
+    f(6, "temperature_feels_like", "C"),
+    f(7, "relative_humidity", ""),
+    f(8, "location", ""),
+    f(9, "observed_at_time", ""),
+    f(10, "observed_location_lat", "semicircles"),
+    f(11, "observed_location_long", "semicircles"),
+    f(12, "day_of_week", ""),
+    f(13, "high_temperature", "C"),
+    f(14, "low_temperature", "C"),
+];
+
+const WEATHER_ALERT: &[FieldInfo] = &[
+    f(0, "report_id", ""),
+    f(1, "issue_time", ""),
+    f(2, "expire_time", ""),
+    f(3, "severity", ""),
+    f(4, "type", ""),
+];
+
+const CADENCE_ZONE: &[FieldInfo] = &[f(0, "high_value", "rpm"), f(1, "name", "")];
+
+const HR: &[FieldInfo] = &[
+    s(0, "fractional_timestamp", "s", 32768.0),
+    s(1, "time256", "s", 256.0),
+    f(6, "filtered_bpm", "bpm"),
+    s(9, "event_timestamp", "s", 1024.0),
+    s(10, "event_timestamp_12", "s", 1024.0),
+];
+
+const SEGMENT_LAP: &[FieldInfo] = &[
+    f(0, "event", ""),
+    f(1, "event_type", ""),
+    f(2, "start_time", ""),
+    f(3, "start_position_lat", "semicircles"),
+    f(4, "start_position_long", "semicircles"),
+    f(5, "end_position_lat", "semicircles"),
+    f(6, "end_position_long", "semicircles"),
+    s(7, "total_elapsed_time", "s", 1000.0),
+    s(8, "total_timer_time", "s", 1000.0),
+    s(9, "total_distance", "m", 100.0),
+    f(10, "total_cycles", "cycles"),
+    f(11, "total_calories", "kcal"),
+    f(12, "total_fat_calories", "kcal"),
+    s(13, "avg_speed", "m/s", 1000.0),
+    s(14, "max_speed", "m/s", 1000.0),
+    f(15, "avg_heart_rate", "bpm"),
+    f(16, "max_heart_rate", "bpm"),
+    f(17, "avg_cadence", "rpm"),
+    f(18, "max_cadence", "rpm"),
+    f(19, "avg_power", "watts"),
+    f(20, "max_power", "watts"),
+    f(21, "total_ascent", "m"),
+    f(22, "total_descent", "m"),
+    f(23, "sport", ""),
+    f(24, "event_group", ""),
+    f(25, "nec_lat", "semicircles"),
+    f(26, "nec_long", "semicircles"),
+    f(27, "swc_lat", "semicircles"),
+    f(28, "swc_long", "semicircles"),
+    f(29, "name", ""),
+    f(30, "normalized_power", "watts"),
+    f(31, "left_right_balance", ""),
+    f(32, "sub_sport", ""),
+    f(33, "total_work", "J"),
+    so(34, "avg_altitude", "m", 5.0, 500.0),
+    so(35, "max_altitude", "m", 5.0, 500.0),
+    f(36, "gps_accuracy", "m"),
+    s(37, "avg_grade", "%", 100.0),
+    s(38, "avg_pos_grade", "%", 100.0),
+    s(39, "avg_neg_grade", "%", 100.0),
+    s(40, "max_pos_grade", "%", 100.0),
+    s(41, "max_neg_grade", "%", 100.0),
+    f(42, "avg_temperature", "C"),
+    f(43, "max_temperature", "C"),
+    s(44, "total_moving_time", "s", 1000.0),
+    s(45, "avg_pos_vertical_speed", "m/s", 1000.0),
+    s(46, "avg_neg_vertical_speed", "m/s", 1000.0),
+    s(47, "max_pos_vertical_speed", "m/s", 1000.0),
+    s(48, "max_neg_vertical_speed", "m/s", 1000.0),
+    s(49, "time_in_hr_zone", "s", 1000.0),
+    s(50, "time_in_speed_zone", "s", 1000.0),
+    s(51, "time_in_cadence_zone", "s", 1000.0),
+    s(52, "time_in_power_zone", "s", 1000.0),
+    f(53, "repetition_num", ""),
+    so(54, "min_altitude", "m", 5.0, 500.0),
+    f(55, "min_heart_rate", "bpm"),
+    s(56, "active_time", "s", 1000.0),
+    f(57, "wkt_step_index", ""),
+    f(58, "sport_event", ""),
+    s(59, "avg_left_torque_effectiveness", "percent", 2.0),
+    s(60, "avg_right_torque_effectiveness", "percent", 2.0),
+    s(61, "avg_left_pedal_smoothness", "percent", 2.0),
+    s(62, "avg_right_pedal_smoothness", "percent", 2.0),
+    s(63, "avg_combined_pedal_smoothness", "percent", 2.0),
+    f(64, "status", ""),
+    f(65, "uuid", ""),
+    s(66, "avg_fractional_cadence", "rpm", 128.0),
+    s(67, "max_fractional_cadence", "rpm", 128.0),
+    s(68, "total_fractional_cycles", "cycles", 128.0),
+    f(69, "front_gear_shift_count", ""),
+    f(70, "rear_gear_shift_count", ""),
+    s(71, "time_standing", "s", 1000.0),
+    f(72, "stand_count", ""),
+    f(73, "avg_left_pco", "mm"),
+    f(74, "avg_right_pco", "mm"),
+    s(75, "avg_left_power_phase", "degrees", 0.7111111),
+    s(76, "avg_left_power_phase_peak", "degrees", 0.7111111),
+    s(77, "avg_right_power_phase", "degrees", 0.7111111),
+    s(78, "avg_right_power_phase_peak", "degrees", 0.7111111),
+    f(79, "avg_power_position", "watts"),
+    f(80, "max_power_position", "watts"),
+    f(81, "avg_cadence_position", "rpm"),
+    f(82, "max_cadence_position", "rpm"),
+    f(83, "manufacturer", ""),
+    f(84, "total_grit", "kGrit"),
+    f(85, "total_flow", "Flow"),
+    f(86, "avg_grit", "kGrit"),
+    f(87, "avg_flow", "Flow"),
+    s(89, "total_fractional_ascent", "m", 100.0),
+    s(90, "total_fractional_descent", "m", 100.0),
+    so(91, "enhanced_avg_altitude", "m", 5.0, 500.0),
+    so(92, "enhanced_max_altitude", "m", 5.0, 500.0),
+    so(93, "enhanced_min_altitude", "m", 5.0, 500.0),
+];
+
+const MEMO_GLOB: &[FieldInfo] = &[
+    f(0, "memo", ""),
+    f(1, "mesg_num", ""),
+    f(2, "parent_index", ""),
+    f(3, "field_num", ""),
+    f(4, "data", ""),
+    f(250, "part_index", ""),
+];
+
+const SEGMENT_ID: &[FieldInfo] = &[
+    f(0, "name", ""),
+    f(1, "uuid", ""),
+    f(2, "sport", ""),
+    f(3, "enabled", ""),
+    f(4, "user_profile_primary_key", ""),
+    f(5, "device_id", ""),
+    f(6, "default_race_leader", ""),
+    f(7, "delete_status", ""),
+    f(8, "selection_type", ""),
+];
+
+const SEGMENT_LEADERBOARD_ENTRY: &[FieldInfo] = &[
+    f(0, "name", ""),
+    f(1, "type", ""),
+    f(2, "group_primary_key", ""),
+    f(3, "activity_id", ""),
+    s(4, "segment_time", "s", 1000.0),
+    f(5, "activity_id_string", ""),
+];
+
+const SEGMENT_POINT: &[FieldInfo] = &[
+    f(1, "position_lat", "semicircles"),
+    f(2, "position_long", "semicircles"),
+    s(3, "distance", "m", 100.0),
+    so(4, "altitude", "m", 5.0, 500.0),
+    s(5, "leader_time", "s", 1000.0),
+    so(6, "enhanced_altitude", "m", 5.0, 500.0),
+];
+
+const SEGMENT_FILE: &[FieldInfo] = &[
+    f(1, "file_uuid", ""),
+    f(3, "enabled", ""),
+    f(4, "user_profile_primary_key", ""),
+    f(7, "leader_type", ""),
+    f(8, "leader_group_primary_key", ""),
+    f(9, "leader_activity_id", ""),
+    f(10, "leader_activity_id_string", ""),
+    f(11, "default_race_leader", ""),
+];
+
+const WORKOUT_SESSION: &[FieldInfo] = &[
+    f(0, "sport", ""),
+    f(1, "sub_sport", ""),
+    f(2, "num_valid_steps", ""),
+    f(3, "first_step_index", ""),
+    s(4, "pool_length", "m", 100.0),
+    f(5, "pool_length_unit", ""),
+];
+
+const WATCHFACE_SETTINGS: &[FieldInfo] = &[f(0, "mode", ""), f(1, "layout", "")];
+
+const GPS_METADATA: &[FieldInfo] = &[
+    f(0, "timestamp_ms", "ms"),
+    f(1, "position_lat", "semicircles"),
+    f(2, "position_long", "semicircles"),
+    so(3, "enhanced_altitude", "m", 5.0, 500.0),
+    s(4, "enhanced_speed", "m/s", 1000.0),
+    s(5, "heading", "degrees", 100.0),
+    f(6, "utc_timestamp", "s"),
+    s(7, "velocity", "m/s", 100.0),
+];
+
+const CAMERA_EVENT: &[FieldInfo] = &[
+    f(0, "timestamp_ms", "ms"),
+    f(1, "camera_event_type", ""),
+    f(2, "camera_file_uuid", ""),
+    f(3, "camera_orientation", ""),
+];
+
+const TIMESTAMP_CORRELATION: &[FieldInfo] = &[
+    s(0, "fractional_timestamp", "s", 32768.0),
+    f(1, "system_timestamp", "s"),
+    s(2, "fractional_system_timestamp", "s", 32768.0),
+    f(3, "local_timestamp", "s"),
+    f(4, "timestamp_ms", "ms"),
+    f(5, "system_timestamp_ms", "ms"),
+];
+
+const GYROSCOPE_DATA: &[FieldInfo] = &[
+    f(0, "timestamp_ms", "ms"),
+    f(1, "sample_time_offset", "ms"),
+    f(2, "gyro_x", "counts"),
+    f(3, "gyro_y", "counts"),
+    f(4, "gyro_z", "counts"),
+    f(5, "calibrated_gyro_x", "deg/s"),
+    f(6, "calibrated_gyro_y", "deg/s"),
+    f(7, "calibrated_gyro_z", "deg/s"),
+];
+
+const ACCELEROMETER_DATA: &[FieldInfo] = &[
+    f(0, "timestamp_ms", "ms"),
+    f(1, "sample_time_offset", "ms"),
+    f(2, "accel_x", "counts"),
+    f(3, "accel_y", "counts"),
+    f(4, "accel_z", "counts"),
+    f(5, "calibrated_accel_x", "g"),
+    f(6, "calibrated_accel_y", "g"),
+    f(7, "calibrated_accel_z", "g"),
+    f(8, "compressed_calibrated_accel_x", "mG"),
+    f(9, "compressed_calibrated_accel_y", "mG"),
+    f(10, "compressed_calibrated_accel_z", "mG"),
+];
+
+const THREE_D_SENSOR_CALIBRATION: &[FieldInfo] = &[
+    f(0, "sensor_type", ""),
+    f(1, "calibration_factor", ""),
+    f(2, "calibration_divisor", "counts"),
+    f(3, "level_shift", ""),
+    f(4, "offset_cal", ""),
+    s(5, "orientation_matrix", "", 65535.0),
+];
+
+const VIDEO_FRAME: &[FieldInfo] = &[f(0, "timestamp_ms", "ms"), f(1, "frame_number", "")];
+
+const OBDII_DATA: &[FieldInfo] = &[
+    f(0, "timestamp_ms", "ms"),
+    f(1, "time_offset", "ms"),
+    f(2, "pid", ""),
+    f(3, "raw_data", ""),
+    f(4, "pid_data_size", ""),
+    f(5, "system_time", ""),
+    f(6, "start_timestamp", ""),
+    f(7, "start_timestamp_ms", "ms"),
+];
+
+const NMEA_SENTENCE: &[FieldInfo] = &[f(0, "timestamp_ms", "ms"), f(1, "sentence", "")];
+
+const AVIATION_ATTITUDE: &[FieldInfo] = &[
+    f(0, "timestamp_ms", "ms"),
+    f(1, "system_time", "ms"),
+    s(2, "pitch", "radians", 10430.38),
+    s(3, "roll", "radians", 10430.38),
+    s(4, "accel_lateral", "m/s^2", 100.0),
+    s(5, "accel_normal", "m/s^2", 100.0),
+    s(6, "turn_rate", "radians/second", 1024.0),
+    f(7, "stage", ""),
+    f(8, "attitude_stage_complete", "%"),
+    s(9, "track", "radians", 10430.38),
+    f(10, "validity", ""),
+];
+
+const VIDEO: &[FieldInfo] = &[
+    f(0, "url", ""),
+    f(1, "hosting_provider", ""),
+    f(2, "duration", "ms"),
+];
+
+const VIDEO_TITLE: &[FieldInfo] = &[f(0, "message_count", ""), f(1, "text", "")];
+
+const VIDEO_DESCRIPTION: &[FieldInfo] = &[f(0, "message_count", ""), f(1, "text", "")];
+
+const VIDEO_CLIP: &[FieldInfo] = &[
+    f(0, "clip_number", ""),
+    f(1, "start_timestamp", ""),
+    f(2, "start_timestamp_ms", ""),
+    f(3, "end_timestamp", ""),
+    f(4, "end_timestamp_ms", ""),
+    f(6, "clip_start", "ms"),
+    f(7, "clip_end", "ms"),
+];
+
+const OHR_SETTINGS: &[FieldInfo] = &[f(0, "enabled", "")];
+
+const EXD_SCREEN_CONFIGURATION: &[FieldInfo] = &[
+    f(0, "screen_index", ""),
+    f(1, "field_count", ""),
+    f(2, "layout", ""),
+    f(3, "screen_enabled", ""),
+];
+
+const EXD_DATA_FIELD_CONFIGURATION: &[FieldInfo] = &[
+    f(0, "screen_index", ""),
+    f(1, "concept_field", ""),
+    f(2, "field_id", ""),
+    f(3, "concept_count", ""),
+    f(4, "display_type", ""),
+    f(5, "title", ""),
+];
+
+const EXD_DATA_CONCEPT_CONFIGURATION: &[FieldInfo] = &[
+    f(0, "screen_index", ""),
+    f(1, "concept_field", ""),
+    f(2, "field_id", ""),
+    f(3, "concept_index", ""),
+    f(4, "data_page", ""),
+    f(5, "concept_key", ""),
+    f(6, "scaling", ""),
+    f(8, "data_units", ""),
+    f(9, "qualifier", ""),
+    f(10, "descriptor", ""),
+    f(11, "is_signed", ""),
+];
+
+const FIELD_DESCRIPTION: &[FieldInfo] = &[
+    f(0, "developer_data_index", ""),
+    f(1, "field_definition_number", ""),
+    f(2, "fit_base_type_id", ""),
+    f(3, "field_name", ""),
+    f(4, "array", ""),
+    f(5, "components", ""),
+    f(6, "scale", ""),
+    f(7, "offset", ""),
+    f(8, "units", ""),
+    f(9, "bits", ""),
+    f(10, "accumulate", ""),
+    f(13, "fit_base_unit_id", ""),
+    f(14, "native_mesg_num", ""),
+    f(15, "native_field_num", ""),
+];
+
+const DEVELOPER_DATA_ID: &[FieldInfo] = &[
+    f(0, "developer_id", ""),
+    f(1, "application_id", ""),
+    f(2, "manufacturer_id", ""),
+    f(3, "developer_data_index", ""),
+    f(4, "application_version", ""),
+];
+
+const MAGNETOMETER_DATA: &[FieldInfo] = &[
+    f(0, "timestamp_ms", "ms"),
+    f(1, "sample_time_offset", "ms"),
+    f(2, "mag_x", "counts"),
+    f(3, "mag_y", "counts"),
+    f(4, "mag_z", "counts"),
+    f(5, "calibrated_mag_x", "G"),
+    f(6, "calibrated_mag_y", "G"),
+    f(7, "calibrated_mag_z", "G"),
+];
+
+const BAROMETER_DATA: &[FieldInfo] = &[
+    f(0, "timestamp_ms", "ms"),
+    f(1, "sample_time_offset", "ms"),
+    f(2, "baro_pres", "Pa"),
+];
+
+const ONE_D_SENSOR_CALIBRATION: &[FieldInfo] = &[
+    f(0, "sensor_type", ""),
+    f(1, "calibration_factor", ""),
+    f(2, "calibration_divisor", "counts"),
+    f(3, "level_shift", ""),
+    f(4, "offset_cal", ""),
+];
+
+const MONITORING_HR_DATA: &[FieldInfo] = &[
+    f(0, "resting_heart_rate", "bpm"),
+    f(1, "current_day_resting_heart_rate", "bpm"),
+];
+
+const TIME_IN_ZONE: &[FieldInfo] = &[
+    f(0, "reference_mesg", ""),
+    f(1, "reference_index", ""),
+    s(2, "time_in_hr_zone", "s", 1000.0),
+    s(3, "time_in_speed_zone", "s", 1000.0),
+    s(4, "time_in_cadence_zone", "s", 1000.0),
+    s(5, "time_in_power_zone", "s", 1000.0),
+    f(6, "hr_zone_high_boundary", "bpm"),
+    s(7, "speed_zone_high_boundary", "m/s", 1000.0),
+    f(8, "cadence_zone_high_bondary", "rpm"),
+    f(9, "power_zone_high_boundary", "watts"),
+    f(10, "hr_calc_type", ""),
+    f(11, "max_heart_rate", ""),
+    f(12, "resting_heart_rate", ""),
+    f(13, "threshold_heart_rate", ""),
+    f(14, "pwr_calc_type", ""),
+    f(15, "functional_threshold_power", ""),
+];
+
+// the timestamp of a set is field 254, which other messages use for message_index
+const SET: &[FieldInfo] = &[
+    s(0, "duration", "s", 1000.0),
+    f(3, "repetitions", ""),
+    s(4, "weight", "kg", 16.0),
+    f(5, "set_type", ""),
+    f(6, "start_time", ""),
+    f(7, "category", ""),
+    f(8, "category_subtype", ""),
+    f(9, "weight_display_unit", ""),
+    f(10, "message_index", ""),
+    f(11, "wkt_step_index", ""),
+    f(254, "timestamp", "s"),
+];
+
+const STRESS_LEVEL: &[FieldInfo] = &[
+    f(0, "stress_level_value", ""),
+    f(1, "stress_level_time", "s"),
+];
+
+const MAX_MET_DATA: &[FieldInfo] = &[
+    f(0, "update_time", ""),
+    s(2, "vo2_max", "mL/kg/min", 10.0),
+    f(5, "sport", ""),
+    f(6, "sub_sport", ""),
+    f(8, "max_met_category", ""),
+    f(9, "calibrated_data", ""),
+    f(12, "hr_source", ""),
+    f(13, "speed_source", ""),
+];
+
+const DIVE_SETTINGS: &[FieldInfo] = &[
+    f(0, "name", ""),
+    f(1, "model", ""),
+    f(2, "gf_low", "percent"),
+    f(3, "gf_high", "percent"),
+    f(4, "water_type", ""),
+    f(5, "water_density", "kg/m^3"),
+    s(6, "po2_warn", "percent", 100.0),
+    s(7, "po2_critical", "percent", 100.0),
+    s(8, "po2_deco", "percent", 100.0),
+    f(9, "safety_stop_enabled", ""),
+    f(10, "bottom_depth", ""),
+    f(11, "bottom_time", ""),
+    f(12, "apnea_countdown_enabled", ""),
+    f(13, "apnea_countdown_time", ""),
+    f(14, "backlight_mode", ""),
+    f(15, "backlight_brightness", ""),
+    f(16, "backlight_timeout", ""),
+    f(17, "repeat_dive_interval", "s"),
+    f(18, "safety_stop_time", "s"),
+    f(19, "heart_rate_source_type", ""),
+    f(20, "heart_rate_source", ""),
+    f(21, "travel_gas", ""),
+    f(22, "ccr_low_setpoint_switch_mode", ""),
+    s(23, "ccr_low_setpoint", "percent", 100.0),
+    s(24, "ccr_low_setpoint_depth", "m", 1000.0),
+    f(25, "ccr_high_setpoint_switch_mode", ""),
+    s(26, "ccr_high_setpoint", "percent", 100.0),
+    s(27, "ccr_high_setpoint_depth", "m", 1000.0),
+    f(29, "gas_consumption_display", ""),
+    f(30, "up_key_enabled", ""),
+    f(35, "dive_sounds", ""),
+    s(36, "last_stop_multiple", "", 10.0),
+    f(37, "no_fly_time_mode", ""),
+];
+
+const DIVE_GAS: &[FieldInfo] = &[
+    f(0, "helium_content", "percent"),
+    f(1, "oxygen_content", "percent"),
+    f(2, "status", ""),
+    f(3, "mode", ""),
+];
+
+const DIVE_ALARM: &[FieldInfo] = &[
+    s(0, "depth", "m", 1000.0),
+    f(1, "time", "s"),
+    f(2, "enabled", ""),
+    f(3, "alarm_type", ""),
+    f(4, "sound", ""),
+    f(5, "dive_types", ""),
+    f(6, "id", ""),
+    f(7, "popup_enabled", ""),
+    f(8, "trigger_on_descent", ""),
+    f(9, "trigger_on_ascent", ""),
+    f(10, "repeating", ""),
+    s(11, "speed", "mps", 1000.0),
+];
+
+const EXERCISE_TITLE: &[FieldInfo] = &[
+    f(0, "exercise_category", ""),
+    f(1, "exercise_name", ""),
+    f(2, "wkt_step_name", ""),
+];
+
+const DIVE_SUMMARY: &[FieldInfo] = &[
+    f(0, "reference_mesg", ""),
+    f(1, "reference_index", ""),
+    s(2, "avg_depth", "m", 1000.0),
+    s(3, "max_depth", "m", 1000.0),
+    f(4, "surface_interval", "s"),
+    f(5, "start_cns", "percent"),
+    f(6, "end_cns", "percent"),
+    f(7, "start_n2", "percent"),
+    f(8, "end_n2", "percent"),
+    f(9, "o2_toxicity", "OTUs"),
+    f(10, "dive_number", ""),
+    s(11, "bottom_time", "s", 1000.0),
+    s(12, "avg_pressure_sac", "bar/min", 100.0),
+    s(13, "avg_volume_sac", "L/min", 100.0),
+    s(14, "avg_rmv", "L/min", 100.0),
+    s(15, "descent_time", "s", 1000.0),
+    s(16, "ascent_time", "s", 1000.0),
+    s(17, "avg_ascent_rate", "m/s", 1000.0),
+    s(22, "avg_descent_rate", "m/s", 1000.0),
+    s(23, "max_ascent_rate", "m/s", 1000.0),
+    s(24, "max_descent_rate", "m/s", 1000.0),
+    s(25, "hang_time", "s", 1000.0),
+];
+
+const SPO2_DATA: &[FieldInfo] = &[
+    f(0, "reading_spo2", "percent"),
+    f(1, "reading_confidence", ""),
+    f(2, "mode", ""),
+];
+
+const SLEEP_LEVEL: &[FieldInfo] = &[f(0, "sleep_level", "")];
+
+const JUMP: &[FieldInfo] = &[
+    f(0, "distance", "m"),
+    f(1, "height", "m"),
+    f(2, "rotations", ""),
+    f(3, "hang_time", "s"),
+    f(4, "score", ""),
+    f(5, "position_lat", "semicircles"),
+    f(6, "position_long", "semicircles"),
+    s(7, "speed", "m/s", 1000.0),
+    s(8, "enhanced_speed", "m/s", 1000.0),
+];
+
+const BEAT_INTERVALS: &[FieldInfo] = &[f(0, "timestamp_ms", "ms"), f(1, "time", "ms")];
+
+const RESPIRATION_RATE: &[FieldInfo] = &[s(0, "respiration_rate", "breaths/min", 100.0)];
+
+const HSA_ACCELEROMETER_DATA: &[FieldInfo] = &[
+    f(0, "timestamp_ms", "ms"),
+    f(1, "sampling_interval", "1/32768 s"),
+    s(2, "accel_x", "mG", 1.024),
+    s(3, "accel_y", "mG", 1.024),
+    s(4, "accel_z", "mG", 1.024),
+    f(5, "timestamp_32k", "1/32768 s"),
+];
+
+const HSA_STEP_DATA: &[FieldInfo] = &[f(0, "processing_interval", "s"), f(1, "steps", "steps")];
+
+const HSA_SPO2_DATA: &[FieldInfo] = &[
+    f(0, "processing_interval", "s"),
+    f(1, "reading_spo2", "percent"),
+    f(2, "confidence", ""),
+];
+
+const HSA_STRESS_DATA: &[FieldInfo] =
+    &[f(0, "processing_interval", "s"), f(1, "stress_level", "s")];
+
+const HSA_RESPIRATION_DATA: &[FieldInfo] = &[
+    f(0, "processing_interval", "s"),
+    s(1, "respiration_rate", "breaths/min", 100.0),
+];
+
+const HSA_HEART_RATE_DATA: &[FieldInfo] = &[
+    f(0, "processing_interval", "s"),
+    f(1, "status", ""),
+    f(2, "heart_rate", "bpm"),
+];
+
+const SPLIT: &[FieldInfo] = &[
+    f(0, "split_type", ""),
+    s(1, "total_elapsed_time", "s", 1000.0),
+    s(2, "total_timer_time", "s", 1000.0),
+    s(3, "total_distance", "m", 100.0),
+    s(4, "avg_speed", "m/s", 1000.0),
+    f(9, "start_time", ""),
+    f(13, "total_ascent", "m"),
+    f(14, "total_descent", "m"),
+    f(21, "start_position_lat", "semicircles"),
+    f(22, "start_position_long", "semicircles"),
+    f(23, "end_position_lat", "semicircles"),
+    f(24, "end_position_long", "semicircles"),
+    s(25, "max_speed", "m/s", 1000.0),
+    s(26, "avg_vert_speed", "m/s", 1000.0),
+    f(27, "end_time", ""),
+    f(28, "total_calories", "kcal"),
+    so(74, "start_elevation", "m", 5.0, 500.0),
+    s(110, "total_moving_time", "s", 1000.0),
+];
+
+const SPLIT_SUMMARY: &[FieldInfo] = &[
+    f(0, "split_type", ""),
+    f(3, "num_splits", ""),
+    s(4, "total_timer_time", "s", 1000.0),
+    s(5, "total_distance", "m", 100.0),
+    s(6, "avg_speed", "m/s", 1000.0),
+    s(7, "max_speed", "m/s", 1000.0),
+    f(8, "total_ascent", "m"),
+    f(9, "total_descent", "m"),
+    f(10, "avg_heart_rate", "bpm"),
+    f(11, "max_heart_rate", "bpm"),
+    s(12, "avg_vert_speed", "m/s", 1000.0),
+    f(13, "total_calories", "kcal"),
+    s(77, "total_moving_time", "s", 1000.0),
+];
+
+const HSA_BODY_BATTERY_DATA: &[FieldInfo] = &[
+    f(0, "processing_interval", "s"),
+    f(1, "level", "percent"),
+    f(2, "charged", ""),
+    f(3, "uncharged", ""),
+];
+
+const HSA_EVENT: &[FieldInfo] = &[f(0, "event_id", "")];
+
+const CLIMB_PRO: &[FieldInfo] = &[
+    f(0, "position_lat", "semicircles"),
+    f(1, "position_long", "semicircles"),
+    f(2, "climb_pro_event", ""),
+    f(3, "climb_number", ""),
+    f(4, "climb_category", ""),
+    f(5, "current_dist", "m"),
+];
+
+const TANK_UPDATE: &[FieldInfo] = &[f(0, "sensor", ""), s(1, "pressure", "bar", 100.0)];
+
+const TANK_SUMMARY: &[FieldInfo] = &[
+    f(0, "sensor", ""),
+    s(1, "start_pressure", "bar", 100.0),
+    s(2, "end_pressure", "bar", 100.0),
+    s(3, "volume_used", "L", 100.0),
+];
+
+const SLEEP_ASSESSMENT: &[FieldInfo] = &[
+    f(0, "combined_awake_score", ""),
+    f(1, "awake_time_score", ""),
+    f(2, "awakenings_count_score", ""),
+    f(3, "deep_sleep_score", ""),
+    f(4, "sleep_duration_score", ""),
+    f(5, "light_sleep_score", ""),
+    f(6, "overall_sleep_score", ""),
+    f(7, "sleep_quality_score", ""),
+    f(8, "sleep_recovery_score", ""),
+    f(9, "rem_sleep_score", ""),
+    f(10, "sleep_restlessness_score", ""),
+    f(11, "awakenings_count", ""),
+    f(14, "interruptions_score", ""),
+    s(15, "average_stress_during_sleep", "", 100.0),
+];
+
+const HRV_STATUS_SUMMARY: &[FieldInfo] = &[
+    s(0, "weekly_average", "ms", 128.0),
+    s(1, "last_night_average", "ms", 128.0),
+    s(2, "last_night_5_min_high", "ms", 128.0),
+    s(3, "baseline_low_upper", "ms", 128.0),
+    s(4, "baseline_balanced_lower", "ms", 128.0),
+    s(5, "baseline_balanced_upper", "ms", 128.0),
+    f(6, "status", ""),
+];
+
+const HRV_VALUE: &[FieldInfo] = &[s(0, "value", "ms", 128.0)];
+
+const RAW_BBI: &[FieldInfo] = &[
+    f(0, "timestamp_ms", "ms"),
+    f(1, "data", ""),
+    f(2, "time", "ms"),
+    f(3, "quality", ""),
+    f(4, "gap", ""),
+];
+
+const DEVICE_AUX_BATTERY_INFO: &[FieldInfo] = &[
+    f(0, "device_index", ""),
+    s(1, "battery_voltage", "V", 256.0),
+    f(2, "battery_status", ""),
+    f(3, "battery_identifier", ""),
+];
+
+const HSA_GYROSCOPE_DATA: &[FieldInfo] = &[
+    f(0, "timestamp_ms", "ms"),
+    f(1, "sampling_interval", "1/32768 s"),
+    s(2, "gyro_x", "deg/s", 28.57143),
+    s(3, "gyro_y", "deg/s", 28.57143),
+    s(4, "gyro_z", "deg/s", 28.57143),
+    f(5, "timestamp_32k", "1/32768 s"),
+];
+
+const CHRONO_SHOT_SESSION: &[FieldInfo] = &[
+    s(0, "min_speed", "m/s", 1000.0),
+    s(1, "max_speed", "m/s", 1000.0),
+    s(2, "avg_speed", "m/s", 1000.0),
+    f(3, "shot_count", ""),
+    f(4, "projectile_type", ""),
+    s(5, "grain_weight", "gr", 10.0),
+    s(6, "standard_deviation", "m/s", 1000.0),
+];
+
+const CHRONO_SHOT_DATA: &[FieldInfo] = &[s(0, "shot_speed", "m/s", 1000.0), f(1, "shot_num", "")];
+
+const HSA_CONFIGURATION_DATA: &[FieldInfo] = &[f(0, "data", ""), f(1, "data_size", "")];
+
+const SKIN_TEMP_OVERNIGHT: &[FieldInfo] = &[
+    f(0, "local_timestamp", ""),
+    f(1, "average_deviation", ""),
+    f(2, "average_7_day_deviation", ""),
+    f(4, "nightly_value", ""),
+];
+
+const HSA_WRIST_TEMPERATURE_DATA: &[FieldInfo] = &[
+    f(0, "processing_interval", "s"),
+    s(1, "value", "degC", 1000.0),
+];
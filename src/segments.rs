@@ -0,0 +1,79 @@
+use crate::decoder::Decoder;
+use crate::error::FitError;
+use crate::types::file_header::FileHeader;
+
+//////////
+//// Segments
+//////////
+
+/// Keeps track of the FIT files chained together in the data, and of when decoding has to
+/// stop, so that `Fit` and `StreamDecoder` treat the start and end of each file the same way.
+#[derive(Default)]
+pub(crate) struct Segments {
+    file_headers: Vec<FileHeader>,
+    crc_valid: Option<bool>,
+    stopped: bool,
+}
+impl Segments {
+    pub fn file_headers(&self) -> &[FileHeader] {
+        &self.file_headers
+    }
+    /// The index of the file being decoded, which is a message's `segment`.
+    pub fn current(&self) -> usize {
+        self.file_headers.len().saturating_sub(1)
+    }
+    /// Whether the CRC at the end of each file matches its contents, or `None` if the end of
+    /// the first file hasn't been reached yet.
+    pub fn crc_valid(&self) -> Option<bool> {
+        self.crc_valid
+    }
+    pub fn is_stopped(&self) -> bool {
+        self.stopped
+    }
+    pub fn stop(&mut self) {
+        self.stopped = true;
+    }
+    /// Starts decoding the file whose header has just been read, returning its header, or
+    /// `None` if there's no other file after the last one.
+    pub fn start(
+        &mut self,
+        header: Result<FileHeader, FitError>,
+        decoder: &mut Decoder,
+    ) -> Result<Option<&FileHeader>, FitError> {
+        let strict = decoder.options().strict;
+        let fh = match header {
+            Ok(fh) => fh,
+            // ignore trailing bytes that aren't another FIT file, unless being strict
+            Err(FitError::BadHeader(_)) if !strict && !self.file_headers.is_empty() => {
+                return Ok(None)
+            }
+            Err(e) => return Err(e),
+        };
+        if strict {
+            fh.check_crc()?;
+        }
+        self.file_headers.push(fh);
+        decoder.reset();
+        Ok(self.file_headers.last())
+    }
+    /// Checks the CRC at the end of a file against the one calculated over its contents,
+    /// which is only an error in strict mode.
+    pub fn end(&mut self, expected: u16, calculated: u16, strict: bool) -> Result<(), FitError> {
+        self.crc_valid = Some(self.crc_valid.unwrap_or(true) && expected == calculated);
+        if strict && expected != calculated {
+            return Err(FitError::CrcMismatch {
+                expected,
+                calculated,
+            });
+        }
+        Ok(())
+    }
+    /// Stops decoding after an error it can't carry on from, or after any error in strict
+    /// mode, returning whether it stopped.
+    pub fn stop_on_error(&mut self, e: &FitError, strict: bool) -> bool {
+        if !e.is_recoverable() || strict {
+            self.stopped = true;
+        }
+        self.stopped
+    }
+}
@@ -0,0 +1,188 @@
+use crate::crc::crc_byte;
+use crate::decoder::Decoder;
+use crate::developer_fields::DeveloperDataId;
+use crate::error::FitError;
+use crate::options::DecodeOptions;
+use crate::segments::Segments;
+use crate::types::definition_record::DefinitionRecord;
+use crate::types::file_header::FileHeader;
+use crate::types::message::Message;
+
+//////////
+//// StreamDecoder
+//////////
+
+/// Decodes FIT data that arrives in chunks, e.g. over a BLE connection. Bytes passed to
+/// `feed` are buffered until they make up a complete record, and the definitions and
+/// developer fields seen so far are kept between calls.
+#[derive(Default)]
+pub struct StreamDecoder {
+    buf: Vec<u8>,
+    /// How much of `buf` has been decoded already. It's only removed once per `feed`, so a
+    /// large chunk doesn't have to be shifted down after every record.
+    start: usize,
+    segments: Segments,
+    in_file: bool,
+    position: u64,
+    data_len: u64,
+    decoder: Decoder,
+    crc: u16,
+}
+impl StreamDecoder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Sets the options that control how values are decoded, replacing the defaults.
+    pub fn options(mut self, options: DecodeOptions) -> Self {
+        *self.decoder.options_mut() = options;
+        self
+    }
+    /// The file header, once enough bytes have been fed to read it.
+    pub fn file_header(&self) -> Option<&FileHeader> {
+        self.segments.file_headers().first()
+    }
+    /// The headers of every FIT file found so far, when several are chained together.
+    pub fn file_headers(&self) -> &[FileHeader] {
+        self.segments.file_headers()
+    }
+    /// Whether every data record described by the last file header, and the CRC that follows
+    /// them, has been decoded.
+    pub fn is_finished(&self) -> bool {
+        !self.in_file && self.segments.crc_valid().is_some()
+    }
+    /// Whether the CRC at the end of each file matches its contents, or `None` if the first
+    /// one hasn't arrived yet.
+    pub fn crc_valid(&self) -> Option<bool> {
+        self.segments.crc_valid()
+    }
+    /// The definition currently in use for each of the 16 local message numbers.
+    pub fn definitions(&self) -> &[Option<DefinitionRecord>] {
+        self.decoder.definitions()
+    }
+    /// The application or device that defined the developer fields with the given data index.
+    pub fn developer_data_id(&self, developer_data_index: u8) -> Option<&DeveloperDataId> {
+        self.decoder.developer_data_id(developer_data_index)
+    }
+    /// How many seconds local time is ahead of UTC in the current file, once a message that
+    /// records both has been decoded.
+    pub fn utc_offset(&self) -> Option<i32> {
+        self.decoder.utc_offset()
+    }
+    /// Buffers `data` and returns an iterator over every message that can now be completed.
+    /// Any messages the iterator isn't advanced over stay buffered for the next call. After an
+    /// error that decoding can't carry on from, nothing more is decoded.
+    pub fn feed(&mut self, data: &[u8]) -> Messages<'_> {
+        if !self.segments.is_stopped() {
+            self.buf.drain(..self.start);
+            self.start = 0;
+            self.buf.extend_from_slice(data);
+        }
+        Messages { stream: self }
+    }
+
+    fn next_message(&mut self) -> Result<Option<Message>, FitError> {
+        loop {
+            if !self.in_file {
+                // the first byte is the size of the header
+                let buf = &self.buf[self.start..];
+                match buf.first() {
+                    Some(size) if buf.len() >= usize::from(*size).max(12) => (),
+                    _ => return Ok(None),
+                }
+                let mut slice = buf;
+                let fh = FileHeader::new(&mut slice);
+                let n = buf.len() - slice.len();
+                self.data_len = match self.segments.start(fh, &mut self.decoder)? {
+                    Some(fh) => fh.data_end(),
+                    None => {
+                        self.discard();
+                        return Ok(None);
+                    }
+                };
+                self.position = 0;
+                self.crc = 0;
+                self.consume(n);
+                self.in_file = true;
+            }
+            if self.position >= self.data_len {
+                if self.buf.len() - self.start < 2 {
+                    return Ok(None);
+                }
+                self.check_crc()?;
+                continue;
+            }
+
+            let buf = &self.buf[self.start..];
+            let mut slice = buf;
+            let result = self.decoder.decode_record(&mut slice, self.position);
+            let n = buf.len() - slice.len();
+            match result {
+                // the rest of this record hasn't arrived yet
+                Err(FitError::TruncatedRecord) => return Ok(None),
+                r => {
+                    self.consume(n);
+                    if let Some(mut m) = r? {
+                        m.segment = self.segments.current();
+                        return Ok(Some(m));
+                    }
+                }
+            }
+        }
+    }
+
+    fn stop_on_error(
+        &mut self,
+        r: Result<Option<Message>, FitError>,
+    ) -> Option<Result<Message, FitError>> {
+        if let Err(e) = &r {
+            // the rest of the buffer can't be trusted to start on a record boundary
+            let strict = self.decoder.options().strict;
+            if self.segments.stop_on_error(e, strict) {
+                self.discard();
+            }
+        }
+        r.transpose()
+    }
+
+    fn check_crc(&mut self) -> Result<(), FitError> {
+        let buf = &self.buf[self.start..];
+        let expected = u16::from_le_bytes([buf[0], buf[1]]);
+        let calculated = self.crc;
+        self.consume(2);
+        self.in_file = false;
+        let strict = self.decoder.options().strict;
+        self.segments.end(expected, calculated, strict)
+    }
+
+    fn consume(&mut self, n: usize) {
+        let end = self.start + n;
+        self.crc = self.buf[self.start..end]
+            .iter()
+            .fold(self.crc, |crc, b| crc_byte(crc, *b));
+        self.start = end;
+        self.position += n as u64;
+    }
+
+    fn discard(&mut self) {
+        self.buf.clear();
+        self.start = 0;
+    }
+}
+
+//////////
+//// Messages
+//////////
+
+pub struct Messages<'a> {
+    stream: &'a mut StreamDecoder,
+}
+impl<'a> Iterator for Messages<'a> {
+    type Item = Result<Message, FitError>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.stream.segments.is_stopped() {
+            return None;
+        }
+        let r = self.stream.next_message();
+        self.stream.stop_on_error(r)
+    }
+}
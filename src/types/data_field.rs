@@ -7,13 +7,28 @@ use crate::Value;
 #[derive(Clone, Debug, PartialEq)]
 pub struct DataField {
     pub field_num: usize,
+    /// The field's name and units in the FIT profile, if it's a field the profile describes.
+    pub name: Option<&'static str>,
+    pub units: Option<&'static str>,
+    /// The name of the subfield the field was resolved to, when its meaning depends on the
+    /// value of another field, e.g. `garmin_product` for `product`. `units` are then the
+    /// subfield's.
+    pub subfield: Option<&'static str>,
     pub value: Value,
+    /// The positions of the elements of an array that hold their base type's invalid value.
+    /// They're kept in place so the other elements' positions don't change, and become NaN
+    /// when the array is scaled.
+    pub invalid_elements: Vec<usize>,
 }
 impl DataField {
     pub fn new(fnum: usize, v: Value) -> Self {
         Self {
             field_num: fnum,
+            name: None,
+            units: None,
+            subfield: None,
             value: v,
+            invalid_elements: Vec::new(),
         }
     }
 }
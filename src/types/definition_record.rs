@@ -1,7 +1,8 @@
-use std::io::{Read, Seek};
+use std::io::Read;
 
 use crate::{
     developer_fields::DeveloperFieldDefinition,
+    error::FitError,
     io::{read_u16, read_u8, skip_bytes, Endianness},
 };
 
@@ -18,39 +19,44 @@ pub struct DefinitionRecord {
     pub developer_fields: Option<Vec<DeveloperFieldDefinition>>,
 }
 impl DefinitionRecord {
-    pub fn new<R>(map: &mut R, dev_fields: bool) -> Self
+    pub fn new<R>(map: &mut R, dev_fields: bool) -> Result<Self, FitError>
     where
-        R: Read + Seek,
+        R: Read,
     {
-        skip_bytes(map, 1);
+        skip_bytes(map, 1)?;
         let mut buffer: Vec<FieldDefinition> = Vec::new();
-        let endian = match read_u8(map) {
+        let endian = match read_u8(map)? {
             1 => Endianness::Big,
             0 => Endianness::Little,
-            _ => panic!("unexpected endian byte"),
+            b => {
+                return Err(FitError::BadDefinition(format!(
+                    "unexpected endian byte: {}",
+                    b
+                )))
+            }
         };
-        let global_message_number = read_u16(map, endian);
-        let number_of_fields = read_u8(map);
+        let global_message_number = read_u16(map, endian)?;
+        let number_of_fields = read_u8(map)?;
 
         for _ in 0..number_of_fields {
-            buffer.push(FieldDefinition::new(map));
+            buffer.push(FieldDefinition::new(map)?);
         }
         let dev_fields: Option<Vec<DeveloperFieldDefinition>> = if dev_fields {
-            let number_of_fields = read_u8(map);
+            let number_of_fields = read_u8(map)?;
             Some(
                 (0..number_of_fields)
                     .map(|_| DeveloperFieldDefinition::new(map))
-                    .collect(),
+                    .collect::<Result<_, _>>()?,
             )
         } else {
             None
         };
 
-        DefinitionRecord {
+        Ok(DefinitionRecord {
             endianness: endian,
             global_message_number,
             field_definitions: buffer,
             developer_fields: dev_fields,
-        }
+        })
     }
 }
@@ -1,3 +1,4 @@
+use crate::developer_fields::DeveloperFieldDescription;
 use crate::Value;
 
 //////////
@@ -8,13 +9,17 @@ use crate::Value;
 pub struct DevDataField {
     pub data_index: u8,
     pub field_num: u8,
+    pub name: String,
+    pub units: Option<String>,
     pub value: Value,
 }
 impl DevDataField {
-    pub fn new(ddi: u8, fnum: u8, v: Value) -> Self {
+    pub fn new(description: &DeveloperFieldDescription, v: Value) -> Self {
         Self {
-            data_index: ddi,
-            field_num: fnum,
+            data_index: description.developer_data_index,
+            field_num: description.field_definition_number,
+            name: description.field_name.clone(),
+            units: description.units.clone(),
             value: v,
         }
     }
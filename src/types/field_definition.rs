@@ -1,6 +1,6 @@
 use std::io::Read;
 
-use crate::consts::FIELD_DEFINITION_BASE_NUMBER;
+use crate::{consts::FIELD_DEFINITION_BASE_NUMBER, error::FitError};
 
 //////////
 //// FieldDefinition
@@ -13,17 +13,16 @@ pub struct FieldDefinition {
     pub base_type: u8,
 }
 impl FieldDefinition {
-    pub fn new<R>(map: &mut R) -> Self
+    pub fn new<R>(map: &mut R) -> Result<Self, FitError>
     where
         R: Read,
     {
         let mut buf: [u8; 3] = [0; 3];
-        let _ = map.read(&mut buf);
-        println!("{:?}", buf);
-        Self {
+        map.read_exact(&mut buf)?;
+        Ok(Self {
             definition_number: buf[0].into(),
             size: buf[1],
             base_type: buf[2] & FIELD_DEFINITION_BASE_NUMBER,
-        }
+        })
     }
 }
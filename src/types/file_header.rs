@@ -1,6 +1,10 @@
-use std::io::Read;
+use std::io::{self, Read};
 
-use crate::io::{arr4, read_u16, read_u32, read_u8, Endianness};
+use crate::{
+    crc::crc16,
+    error::FitError,
+    io::{arr4, read_u16, read_u32, read_u8, skip_bytes, Endianness},
+};
 
 //////////
 //// FileHeader
@@ -13,23 +17,87 @@ pub struct FileHeader {
     pub profile_version: u16,
     pub num_record_bytes: u32,
     pub fileext: bool,
-    pub crc: u16,
+    pub crc: Option<u16>,
+    pub calculated_crc: u16,
 }
 impl FileHeader {
-    pub fn new<R>(map: &mut R) -> Self
+    pub fn new<R>(map: &mut R) -> Result<Self, FitError>
     where
         R: Read,
     {
-        Self {
-            filesize: read_u8(map),
-            protocol: read_u8(map),
-            profile_version: read_u16(map, Endianness::Little),
-            num_record_bytes: read_u32(map, Endianness::Little),
-            fileext: {
-                let buf = arr4(map);
-                &buf == b".FIT"
-            },
-            crc: read_u16(map, Endianness::Little),
+        // every header starts with the same 12 bytes, anything after that depends on its size
+        let mut header: [u8; 12] = [0; 12];
+        map.read_exact(&mut header).map_err(too_short)?;
+        let mut buf = &header[..];
+
+        let filesize = read_u8(&mut buf)?;
+        if filesize < 12 {
+            return Err(FitError::BadHeader(format!(
+                "header size of {} bytes is too small",
+                filesize
+            )));
+        }
+        let protocol = read_u8(&mut buf)?;
+        let profile_version = read_u16(&mut buf, Endianness::Little)?;
+        let num_record_bytes = read_u32(&mut buf, Endianness::Little)?;
+        let fileext = &arr4(&mut buf)? == b".FIT";
+        if !fileext {
+            return Err(FitError::BadHeader("missing .FIT signature".to_string()));
+        }
+
+        let crc = if filesize >= 14 {
+            Some(read_u16(map, Endianness::Little).map_err(too_short)?)
+        } else {
+            None
+        };
+        if filesize > 14 {
+            skip_bytes(map, filesize - 14).map_err(too_short)?;
+        }
+
+        Ok(Self {
+            filesize,
+            protocol,
+            profile_version,
+            num_record_bytes,
+            fileext,
+            crc,
+            calculated_crc: crc16(&header),
+        })
+    }
+    /// Legacy 12 byte headers don't have a CRC.
+    pub fn has_crc(&self) -> bool {
+        self.crc.is_some()
+    }
+    /// Whether the header CRC matches the header bytes. Devices may leave the CRC as zero, in
+    /// which case there's nothing to check.
+    pub fn crc_valid(&self) -> Option<bool> {
+        match self.crc {
+            None | Some(0) => None,
+            Some(crc) => Some(crc == self.calculated_crc),
+        }
+    }
+    /// Returns a `FitError::CrcMismatch` if the header CRC doesn't match.
+    pub(crate) fn check_crc(&self) -> Result<(), FitError> {
+        match (self.crc_valid(), self.crc) {
+            (Some(false), Some(expected)) => Err(FitError::CrcMismatch {
+                expected,
+                calculated: self.calculated_crc,
+            }),
+            _ => Ok(()),
+        }
+    }
+    /// The offset of the file CRC from the start of the header, as it follows the header and all
+    /// of the data records.
+    pub fn data_end(&self) -> u64 {
+        u64::from(self.filesize) + u64::from(self.num_record_bytes)
+    }
+}
+
+fn too_short(e: io::Error) -> FitError {
+    match e.kind() {
+        io::ErrorKind::UnexpectedEof => {
+            FitError::BadHeader("too short to contain a file header".to_string())
         }
+        _ => FitError::Io(e),
     }
 }
@@ -6,6 +6,7 @@ use crate::{
         COMPRESSED_HEADER_TIME_OFFSET_MASK, DEFINITION_HEADER_MASK, DEVELOPER_FIELDS_MASK,
         LOCAL_MESSAGE_NUMBER_MASK,
     },
+    error::FitError,
     io::read_u8,
 };
 
@@ -13,7 +14,7 @@ use crate::{
 //// HeaderByte
 //////////
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct HeaderByte {
     pub compressed_header: bool,
     pub definition: bool,
@@ -22,12 +23,12 @@ pub struct HeaderByte {
     pub time_offset: Option<u8>,
 }
 impl HeaderByte {
-    pub fn new<R>(map: &mut R) -> Self
+    pub fn new<R>(map: &mut R) -> Result<Self, FitError>
     where
         R: Read,
     {
-        let b = read_u8(map);
-        if (b & COMPRESSED_HEADER_MASK) == COMPRESSED_HEADER_MASK {
+        let b = read_u8(map)?;
+        let h = if (b & COMPRESSED_HEADER_MASK) == COMPRESSED_HEADER_MASK {
             Self {
                 compressed_header: true,
                 definition: false,
@@ -43,7 +44,8 @@ impl HeaderByte {
                 local_num: b & LOCAL_MESSAGE_NUMBER_MASK,
                 time_offset: None,
             }
-        }
+        };
+        Ok(h)
     }
     pub fn compressed_timestamp(self) -> Option<u8> {
         if self.compressed_header {
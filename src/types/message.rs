@@ -1,6 +1,8 @@
 use fitsdk::MessageType;
 
-use super::{data_field::DataField, dev_data_field::DevDataField};
+use super::{data_field::DataField, dev_data_field::DevDataField, timestamp::Timestamp};
+use crate::consts::{MFG_RANGE_MAX, MFG_RANGE_MIN};
+use crate::Value;
 
 //////////
 //// Message
@@ -9,6 +11,46 @@ use super::{data_field::DataField, dev_data_field::DevDataField};
 #[derive(Clone, Debug)]
 pub struct Message {
     pub kind: MessageType,
+    /// The message number the file gives this message, which identifies it when `kind` is
+    /// `MessageType::None` because it isn't in the profile.
+    pub global_message_number: u16,
     pub values: Vec<DataField>,
     pub dev_values: Option<Vec<DevDataField>>,
+    /// Which of the FIT files chained together in the data this message came from.
+    pub segment: usize,
+}
+impl Message {
+    /// The value of the field with the given profile name, e.g. `"heart_rate"`, or with the
+    /// name of the subfield it was resolved to.
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        self.field_by_name(name).map(|f| &f.value)
+    }
+    pub fn field_by_name(&self, name: &str) -> Option<&DataField> {
+        self.values
+            .iter()
+            .find(|f| f.name == Some(name) || f.subfield == Some(name))
+    }
+    /// The time of the message, including the fraction of a second that some messages record
+    /// in a `fractional_timestamp` or `timestamp_ms` field.
+    pub fn timestamp(&self) -> Option<Timestamp> {
+        let secs = match self.get("timestamp")? {
+            Value::Time(t) => *t,
+            _ => return None,
+        };
+        let nanos = match (self.get("fractional_timestamp"), self.get("timestamp_ms")) {
+            // a fraction of a second that hasn't been scaled counts 1/32768ths
+            (Some(Value::F64(f)), _) => (f.fract() * 1e9) as u32,
+            (Some(f), _) => f
+                .as_f64()
+                .map_or(0, |f| (f % 32768.0 / 32768.0 * 1e9) as u32),
+            (None, Some(ms)) => ms.as_f64().map_or(0, |ms| (ms % 1000.0 * 1e6) as u32),
+            (None, None) => 0,
+        };
+        Timestamp::new(secs, nanos)
+    }
+    /// Whether this is one of the messages numbered from 0xFF00 that the profile leaves for
+    /// manufacturers to define themselves.
+    pub fn is_manufacturer_specific(&self) -> bool {
+        self.global_message_number >= MFG_RANGE_MIN && self.global_message_number <= MFG_RANGE_MAX
+    }
 }
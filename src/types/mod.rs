@@ -5,3 +5,5 @@ pub mod field_definition;
 pub mod file_header;
 pub mod header_byte;
 pub mod message;
+pub mod record;
+pub mod timestamp;
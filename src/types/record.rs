@@ -0,0 +1,42 @@
+use super::{
+    data_field::DataField, definition_record::DefinitionRecord, dev_data_field::DevDataField,
+    header_byte::HeaderByte,
+};
+
+//////////
+//// Record
+//////////
+
+/// A single record as it appears in a FIT file, before its fields are interpreted using the
+/// profile. `offset` is the position of the record's header byte in the data being decoded.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Record {
+    Definition {
+        local_num: u8,
+        offset: u64,
+        definition: DefinitionRecord,
+    },
+    Data {
+        header: HeaderByte,
+        local_num: u8,
+        global_message_number: u16,
+        offset: u64,
+        /// The bytes of every field, following the header byte.
+        raw_bytes: Vec<u8>,
+        /// The fields decoded by base type alone, with no names, scaling or components.
+        fields: Vec<DataField>,
+        dev_fields: Option<Vec<DevDataField>>,
+    },
+}
+impl Record {
+    pub fn local_num(&self) -> u8 {
+        match self {
+            Record::Definition { local_num, .. } | Record::Data { local_num, .. } => *local_num,
+        }
+    }
+    pub fn offset(&self) -> u64 {
+        match self {
+            Record::Definition { offset, .. } | Record::Data { offset, .. } => *offset,
+        }
+    }
+}
@@ -0,0 +1,160 @@
+use std::convert::TryFrom;
+
+use crate::error::FitError;
+use crate::Value;
+
+//////////
+//// Timestamp
+//////////
+
+/// A point in time in UTC, with the fraction of a second that some messages record alongside
+/// their timestamp.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Timestamp {
+    secs: u32,
+    nanos: u32,
+}
+impl Timestamp {
+    /// Creates a timestamp, carrying any whole seconds in `nanos` over into `secs`, or `None`
+    /// if that takes it past the last time a `u32` can hold.
+    pub fn new(secs: u32, nanos: u32) -> Option<Self> {
+        Some(Self {
+            secs: secs.checked_add(nanos / 1_000_000_000)?,
+            nanos: nanos % 1_000_000_000,
+        })
+    }
+    /// Seconds since the Unix epoch.
+    pub fn secs(&self) -> u32 {
+        self.secs
+    }
+    /// The fraction of a second, which is always less than a whole one.
+    pub fn nanos(&self) -> u32 {
+        self.nanos
+    }
+}
+impl TryFrom<&Value> for Timestamp {
+    type Error = FitError;
+    fn try_from(v: &Value) -> Result<Self, FitError> {
+        match v {
+            Value::Time(t) => Ok(Timestamp { secs: *t, nanos: 0 }),
+            v => Err(FitError::UnexpectedValue {
+                value: v.clone(),
+                expected: "Timestamp",
+            }),
+        }
+    }
+}
+
+//////////
+//// LocalTime
+//////////
+
+/// A time on the clock where the device was, as recorded by fields like `local_timestamp`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LocalTime {
+    /// Seconds since the Unix epoch, counting the local time as if it were UTC.
+    pub secs: u32,
+    /// How many seconds the local time is ahead of UTC, if the file has recorded it by the
+    /// time this value was decoded.
+    pub utc_offset: Option<i32>,
+}
+impl LocalTime {
+    /// The same point in time in UTC, if the offset from UTC is known.
+    pub fn to_utc(&self) -> Option<Timestamp> {
+        let secs = i64::from(self.secs) - i64::from(self.utc_offset?);
+        u32::try_from(secs)
+            .ok()
+            .and_then(|secs| Timestamp::new(secs, 0))
+    }
+}
+impl TryFrom<&Value> for LocalTime {
+    type Error = FitError;
+    fn try_from(v: &Value) -> Result<Self, FitError> {
+        match v {
+            Value::LocalTime(t) => Ok(*t),
+            v => Err(FitError::UnexpectedValue {
+                value: v.clone(),
+                expected: "LocalTime",
+            }),
+        }
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<Timestamp> for chrono::DateTime<chrono::Utc> {
+    fn from(t: Timestamp) -> Self {
+        use chrono::TimeZone;
+        // every u32 number of seconds is well within the range chrono supports, and nanos is
+        // never a whole second or more
+        chrono::Utc
+            .timestamp_opt(i64::from(t.secs), t.nanos)
+            .unwrap()
+    }
+}
+#[cfg(feature = "chrono")]
+impl TryFrom<&Value> for chrono::DateTime<chrono::Utc> {
+    type Error = FitError;
+    fn try_from(v: &Value) -> Result<Self, FitError> {
+        Timestamp::try_from(v).map(Into::into)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<LocalTime> for chrono::DateTime<chrono::FixedOffset> {
+    type Error = FitError;
+    fn try_from(t: LocalTime) -> Result<Self, FitError> {
+        let err = || FitError::UnexpectedValue {
+            value: Value::LocalTime(t),
+            expected: "DateTime<FixedOffset>",
+        };
+        let offset = t
+            .utc_offset
+            .and_then(chrono::FixedOffset::east_opt)
+            .ok_or_else(err)?;
+        let utc = chrono::DateTime::<chrono::Utc>::from(t.to_utc().ok_or_else(err)?);
+        Ok(utc.with_timezone(&offset))
+    }
+}
+#[cfg(feature = "chrono")]
+impl TryFrom<&Value> for chrono::DateTime<chrono::FixedOffset> {
+    type Error = FitError;
+    fn try_from(v: &Value) -> Result<Self, FitError> {
+        LocalTime::try_from(v).and_then(TryFrom::try_from)
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<Timestamp> for time::OffsetDateTime {
+    fn from(t: Timestamp) -> Self {
+        // every u32 number of seconds is well within the range time supports
+        time::OffsetDateTime::from_unix_timestamp(i64::from(t.secs)).unwrap()
+            + time::Duration::nanoseconds(i64::from(t.nanos))
+    }
+}
+#[cfg(feature = "time")]
+impl TryFrom<LocalTime> for time::OffsetDateTime {
+    type Error = FitError;
+    fn try_from(t: LocalTime) -> Result<Self, FitError> {
+        let err = || FitError::UnexpectedValue {
+            value: Value::LocalTime(t),
+            expected: "OffsetDateTime",
+        };
+        let offset = t
+            .utc_offset
+            .and_then(|o| time::UtcOffset::from_whole_seconds(o).ok())
+            .ok_or_else(err)?;
+        let utc = time::OffsetDateTime::from(t.to_utc().ok_or_else(err)?);
+        Ok(utc.to_offset(offset))
+    }
+}
+/// Converts both UTC times and local times whose offset from UTC is known.
+#[cfg(feature = "time")]
+impl TryFrom<&Value> for time::OffsetDateTime {
+    type Error = FitError;
+    fn try_from(v: &Value) -> Result<Self, FitError> {
+        match v {
+            Value::LocalTime(t) => time::OffsetDateTime::try_from(*t),
+            v => Timestamp::try_from(v).map(Into::into),
+        }
+    }
+}
@@ -1,3 +1,8 @@
+use std::convert::TryFrom;
+
+use crate::error::FitError;
+use crate::types::timestamp::LocalTime;
+
 //////////
 //// Value
 //////////
@@ -16,77 +21,270 @@ pub enum Value {
     F64(f64),
     I64(i64),
     U64(u64),
+    /// Seconds since the Unix epoch.
     Time(u32),
+    /// Seconds since the device was turned on, which devices record until they know the time.
+    SystemTime(u32),
+    /// A time on the local clock, with its offset from UTC if that's known.
+    LocalTime(LocalTime),
     ArrU8(Vec<u8>),
+    ArrI8(Vec<i8>),
     ArrU16(Vec<u16>),
+    ArrI16(Vec<i16>),
     ArrU32(Vec<u32>),
+    ArrI32(Vec<i32>),
+    ArrF32(Vec<f32>),
+    ArrF64(Vec<f64>),
+    ArrI64(Vec<i64>),
+    ArrU64(Vec<u64>),
+    Bytes(Vec<u8>),
+    /// A field that holds its base type's invalid value, which is only kept when
+    /// `DecodeOptions::invalid_values` is enabled.
+    Invalid,
 }
-#[allow(unused_must_use)]
 impl Value {
-    pub(super) fn scale(&mut self, val: f32) {
-        match self {
-            Value::U8(mut inner) => {
-                let new_inner = f32::from(inner) / val;
-                std::mem::replace(&mut inner, new_inner as u8);
-            }
-            Value::I8(mut inner) => {
-                let new_inner = f32::from(inner) / val;
-                std::mem::replace(&mut inner, new_inner as i8);
-            }
-            Value::U16(mut inner) => {
-                let new_inner = f32::from(inner) / val;
-                std::mem::replace(&mut inner, new_inner as u16);
-            }
-            Value::I16(mut inner) => {
-                let new_inner = f32::from(inner) / val;
-                std::mem::replace(&mut inner, new_inner as i16);
+    /// The bytes of an integer value, or an array of them, in little-endian order. This is
+    /// the order the bits of component fields are numbered in.
+    pub(crate) fn to_le_bytes(&self) -> Option<Vec<u8>> {
+        let bytes = match self {
+            Value::U8(v) => vec![*v],
+            Value::I8(v) => v.to_le_bytes().to_vec(),
+            Value::U16(v) => v.to_le_bytes().to_vec(),
+            Value::I16(v) => v.to_le_bytes().to_vec(),
+            Value::U32(v) => v.to_le_bytes().to_vec(),
+            Value::I32(v) => v.to_le_bytes().to_vec(),
+            Value::U64(v) => v.to_le_bytes().to_vec(),
+            Value::I64(v) => v.to_le_bytes().to_vec(),
+            Value::ArrU8(v) | Value::Bytes(v) => v.clone(),
+            Value::ArrU16(v) => v.iter().flat_map(|x| x.to_le_bytes().to_vec()).collect(),
+            Value::ArrU32(v) => v.iter().flat_map(|x| x.to_le_bytes().to_vec()).collect(),
+            _ => return None,
+        };
+        Some(bytes)
+    }
+    /// Adds another element to the end of a value, turning a single value into an array.
+    pub(crate) fn push(&mut self, v: Value) {
+        let new = match (self.clone(), v) {
+            (Value::U8(a), Value::U8(b)) => Value::ArrU8(vec![a, b]),
+            (Value::ArrU8(mut a), Value::U8(b)) => {
+                a.push(b);
+                Value::ArrU8(a)
             }
-            Value::U32(mut inner) => {
-                let new_inner = inner as f32 / val;
-                std::mem::replace(&mut inner, new_inner as u32);
+            (Value::U16(a), Value::U16(b)) => Value::ArrU16(vec![a, b]),
+            (Value::ArrU16(mut a), Value::U16(b)) => {
+                a.push(b);
+                Value::ArrU16(a)
             }
-            Value::I32(mut inner) => {
-                let new_inner = inner as f32 / val;
-                std::mem::replace(&mut inner, new_inner as i32);
+            (Value::U32(a), Value::U32(b)) => Value::ArrU32(vec![a, b]),
+            (Value::ArrU32(mut a), Value::U32(b)) => {
+                a.push(b);
+                Value::ArrU32(a)
             }
-            _ => (),
+            _ => return,
+        };
+        *self = new;
+    }
+    /// Converts a numeric value to floating point as `value / scale - offset`, the way FIT
+    /// scales and offsets are applied. Returns `None` for values that aren't numbers.
+    pub(crate) fn scale_offset(&self, scale: f64, offset: f64) -> Option<Value> {
+        let f = |v: f64| v / scale - offset;
+        let v = match self {
+            Value::U8(v) => Value::F64(f(f64::from(*v))),
+            Value::I8(v) => Value::F64(f(f64::from(*v))),
+            Value::U16(v) => Value::F64(f(f64::from(*v))),
+            Value::I16(v) => Value::F64(f(f64::from(*v))),
+            Value::U32(v) => Value::F64(f(f64::from(*v))),
+            Value::I32(v) => Value::F64(f(f64::from(*v))),
+            Value::F32(v) => Value::F64(f(f64::from(*v))),
+            Value::F64(v) => Value::F64(f(*v)),
+            Value::I64(v) => Value::F64(f(*v as f64)),
+            Value::U64(v) => Value::F64(f(*v as f64)),
+            Value::ArrU8(v) => Value::ArrF64(v.iter().map(|x| f(f64::from(*x))).collect()),
+            Value::ArrI8(v) => Value::ArrF64(v.iter().map(|x| f(f64::from(*x))).collect()),
+            Value::ArrU16(v) => Value::ArrF64(v.iter().map(|x| f(f64::from(*x))).collect()),
+            Value::ArrI16(v) => Value::ArrF64(v.iter().map(|x| f(f64::from(*x))).collect()),
+            Value::ArrU32(v) => Value::ArrF64(v.iter().map(|x| f(f64::from(*x))).collect()),
+            Value::ArrI32(v) => Value::ArrF64(v.iter().map(|x| f(f64::from(*x))).collect()),
+            Value::ArrF32(v) => Value::ArrF64(v.iter().map(|x| f(f64::from(*x))).collect()),
+            Value::ArrF64(v) => Value::ArrF64(v.iter().map(|x| f(*x)).collect()),
+            Value::ArrI64(v) => Value::ArrF64(v.iter().map(|x| f(*x as f64)).collect()),
+            Value::ArrU64(v) => Value::ArrF64(v.iter().map(|x| f(*x as f64)).collect()),
+            _ => return None,
+        };
+        Some(v)
+    }
+    /// The value of any numeric variant as an `f64`.
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Value::U8(v) => Some(f64::from(*v)),
+            Value::I8(v) => Some(f64::from(*v)),
+            Value::U16(v) => Some(f64::from(*v)),
+            Value::I16(v) => Some(f64::from(*v)),
+            Value::U32(v) => Some(f64::from(*v)),
+            Value::I32(v) => Some(f64::from(*v)),
+            Value::F32(v) => Some(f64::from(*v)),
+            Value::F64(v) => Some(*v),
+            Value::I64(v) => Some(*v as f64),
+            Value::U64(v) => Some(*v as f64),
+            _ => None,
         }
     }
-    pub(super) fn offset(&mut self, val: i16) {
+    /// The text of a string, or the name of an enum value.
+    pub fn as_str(&self) -> Option<&str> {
         match self {
-            Value::U8(mut inner) => {
-                let new_inner = i16::from(inner) - val;
-                std::mem::replace(&mut inner, new_inner as u8);
-            }
-            Value::I8(mut inner) => {
-                let new_inner = i16::from(inner) - val;
-                std::mem::replace(&mut inner, new_inner as i8);
-            }
-            Value::U16(mut inner) => {
-                let new_inner = inner as i16 - val;
-                std::mem::replace(&mut inner, new_inner as u16);
-            }
-            Value::I16(mut inner) => {
-                let new_inner = inner - val;
-                std::mem::replace(&mut inner, new_inner);
-            }
-            Value::U32(mut inner) => {
-                let new_inner = inner as i16 - val;
-                std::mem::replace(&mut inner, new_inner as u32);
-            }
-            Value::I32(mut inner) => {
-                let new_inner = inner as i16 - val;
-                std::mem::replace(&mut inner, i32::from(new_inner));
-            }
-            _ => (),
+            Value::String(s) => Some(s),
+            Value::Enum(s) => Some(s),
+            _ => None,
+        }
+    }
+    /// The elements of an array, if it's an array of `T`:
+    ///
+    /// ```
+    /// let v = fit::Value::ArrU16(vec![1, 2]);
+    /// assert_eq!(v.as_slice::<u16>(), Some(&[1, 2][..]));
+    /// assert_eq!(v.as_slice::<u8>(), None);
+    /// ```
+    pub fn as_slice<T: ArrayElement>(&self) -> Option<&[T]> {
+        T::slice(self)
+    }
+    pub fn is_array(&self) -> bool {
+        matches!(
+            self,
+            Value::ArrU8(_)
+                | Value::ArrI8(_)
+                | Value::ArrU16(_)
+                | Value::ArrI16(_)
+                | Value::ArrU32(_)
+                | Value::ArrI32(_)
+                | Value::ArrF32(_)
+                | Value::ArrF64(_)
+                | Value::ArrI64(_)
+                | Value::ArrU64(_)
+                | Value::Bytes(_)
+        )
+    }
+    /// The number of elements in an array, which is 1 for any other value except `Invalid`.
+    pub fn len(&self) -> usize {
+        match self {
+            Value::ArrU8(v) | Value::Bytes(v) => v.len(),
+            Value::ArrI8(v) => v.len(),
+            Value::ArrU16(v) => v.len(),
+            Value::ArrI16(v) => v.len(),
+            Value::ArrU32(v) => v.len(),
+            Value::ArrI32(v) => v.len(),
+            Value::ArrF32(v) => v.len(),
+            Value::ArrF64(v) => v.len(),
+            Value::ArrI64(v) => v.len(),
+            Value::ArrU64(v) => v.len(),
+            Value::Invalid => 0,
+            _ => 1,
+        }
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// The value of any integer variant, including `Time`.
+    fn as_i128(&self) -> Option<i128> {
+        match self {
+            Value::U8(v) => Some(i128::from(*v)),
+            Value::I8(v) => Some(i128::from(*v)),
+            Value::U16(v) => Some(i128::from(*v)),
+            Value::I16(v) => Some(i128::from(*v)),
+            Value::U32(v) | Value::Time(v) => Some(i128::from(*v)),
+            Value::I32(v) => Some(i128::from(*v)),
+            Value::I64(v) => Some(i128::from(*v)),
+            Value::U64(v) => Some(i128::from(*v)),
+            _ => None,
         }
     }
 }
-impl From<Value> for u8 {
-    fn from(item: Value) -> Self {
-        match item {
-            Value::U8(v) => v,
-            _ => panic!("can't call this on a non-u8 variant"),
+
+// integers convert from any integer variant whose value fits
+macro_rules! try_from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl TryFrom<&Value> for $t {
+                type Error = FitError;
+                fn try_from(v: &Value) -> Result<Self, FitError> {
+                    v.as_i128()
+                        .and_then(|i| <$t>::try_from(i).ok())
+                        .ok_or_else(|| FitError::UnexpectedValue {
+                            value: v.clone(),
+                            expected: stringify!($t),
+                        })
+                }
+            }
+        )*
+    };
+}
+try_from_integer!(u8, i8, u16, i16, u32, i32, u64, i64);
+
+impl TryFrom<&Value> for f64 {
+    type Error = FitError;
+    fn try_from(v: &Value) -> Result<Self, FitError> {
+        v.as_f64().ok_or_else(|| FitError::UnexpectedValue {
+            value: v.clone(),
+            expected: "f64",
+        })
+    }
+}
+impl TryFrom<&Value> for f32 {
+    type Error = FitError;
+    fn try_from(v: &Value) -> Result<Self, FitError> {
+        match v {
+            Value::F32(v) => Ok(*v),
+            v => f64::try_from(v)
+                .map(|f| f as f32)
+                .map_err(|_| FitError::UnexpectedValue {
+                    value: v.clone(),
+                    expected: "f32",
+                }),
         }
     }
 }
+impl TryFrom<&Value> for String {
+    type Error = FitError;
+    fn try_from(v: &Value) -> Result<Self, FitError> {
+        v.as_str()
+            .map(str::to_owned)
+            .ok_or_else(|| FitError::UnexpectedValue {
+                value: v.clone(),
+                expected: "String",
+            })
+    }
+}
+
+//////////
+//// ArrayElement
+//////////
+
+/// A type that the elements of an array `Value` can have.
+pub trait ArrayElement: Sized {
+    fn slice(v: &Value) -> Option<&[Self]>;
+}
+macro_rules! array_element {
+    ($($t:ty => $($variant:ident)|*),*) => {
+        $(
+            impl ArrayElement for $t {
+                fn slice(v: &Value) -> Option<&[Self]> {
+                    match v {
+                        $(Value::$variant(a) => Some(a),)*
+                        _ => None,
+                    }
+                }
+            }
+        )*
+    };
+}
+array_element!(
+    u8 => ArrU8 | Bytes,
+    i8 => ArrI8,
+    u16 => ArrU16,
+    i16 => ArrI16,
+    u32 => ArrU32,
+    i32 => ArrI32,
+    f32 => ArrF32,
+    f64 => ArrF64,
+    i64 => ArrI64,
+    u64 => ArrU64
+);
@@ -54,7 +54,637 @@ fn it_reads_all_files() {
     }
 }
 
+#[test]
+fn it_fails_to_open_missing_file() {
+    match fit::Fit::open("data/does_not_exist.fit") {
+        Err(fit::FitError::Io(_)) => (),
+        Err(e) => panic!("unexpected error: {}", e),
+        Ok(_) => panic!("opened a missing file"),
+    }
+}
+
+#[test]
+fn it_reports_truncated_files() {
+    let data = std::fs::read("data/garmin_1000.fit").unwrap();
+    let filepath = std::env::temp_dir().join("fit_rs_truncated.fit");
+    std::fs::write(&filepath, &data[..data.len() / 2]).unwrap();
+
+    let mut f = fit::Fit::open(&filepath).unwrap();
+    let results: Vec<_> = f.results().collect();
+    assert!(results.iter().any(|r| r.is_ok()));
+    match results.last() {
+        Some(Err(fit::FitError::TruncatedRecord)) => (),
+        r => panic!("expected a truncated record, got {:?}", r),
+    }
+}
+
+#[test]
+fn it_reads_from_any_source() {
+    let filepath = PathBuf::from("data/garmin_1000.fit");
+    let data = std::fs::read(&filepath).unwrap();
+    let file = std::fs::File::open(&filepath).unwrap();
+
+    let mapped: Vec<_> = fit::Fit::open(&filepath).unwrap().map(|m| m.kind).collect();
+    let bytes: Vec<_> = fit::Fit::from_bytes(&data)
+        .unwrap()
+        .map(|m| m.kind)
+        .collect();
+    let reader: Vec<_> = fit::Fit::from_reader(std::io::BufReader::new(file))
+        .unwrap()
+        .map(|m| m.kind)
+        .collect();
+    assert!(!mapped.is_empty());
+    assert_eq!(mapped, bytes);
+    assert_eq!(mapped, reader);
+}
+
+#[test]
+fn it_decodes_chunked_data() {
+    let data = std::fs::read("data/wahoo_elemnt_dev_fields.fit").unwrap();
+    let expected: Vec<_> = fit::Fit::from_bytes(&data)
+        .unwrap()
+        .map(|m| m.kind)
+        .collect();
+
+    let mut stream = fit::StreamDecoder::new();
+    let mut decoded = Vec::new();
+    for chunk in data.chunks(20) {
+        for m in stream.feed(chunk).filter_map(Result::ok) {
+            decoded.push(m.kind);
+        }
+    }
+    assert!(stream.is_finished());
+    assert_eq!(stream.crc_valid(), Some(true));
+    assert_eq!(decoded, expected);
+}
+
+#[test]
+fn it_stops_streaming_after_an_undefined_local_message() {
+    let data = std::fs::read("data/garmin_1000.fit").unwrap();
+    // a data record for a local message that was never defined, followed by bytes that would
+    // read as a definition if decoding carried on
+    let mut bytes = data[..14].to_vec();
+    bytes.extend_from_slice(&[0x05, 0x45, 0, 0, 20, 0, 1, 3, 1, 2]);
+
+    let mut stream = fit::StreamDecoder::new();
+    let results: Vec<_> = stream.feed(&bytes).collect();
+    assert_eq!(results.len(), 1);
+    assert!(matches!(
+        results[0],
+        Err(fit::FitError::UndefinedLocalMessage(5))
+    ));
+    assert!(stream.definitions().iter().all(Option::is_none));
+    assert_eq!(stream.feed(&data[14..]).count(), 0);
+}
+
+#[test]
+fn it_verifies_crcs() {
+    let data = std::fs::read("data/garmin_1000.fit").unwrap();
+    assert!(fit::verify(&data).is_ok());
+
+    let mut f = fit::Fit::from_bytes(&data)
+        .unwrap()
+        .options(fit::DecodeOptions::new().strict(true));
+    assert_eq!(f.file_header().crc_valid(), Some(true));
+    assert_eq!(f.crc_valid(), None);
+    assert!(f.results().all(|r| r.is_ok()));
+    assert_eq!(f.crc_valid(), Some(true));
+}
+
+#[test]
+fn it_rejects_corrupted_files_in_strict_mode() {
+    let mut data = std::fs::read("data/garmin_1000.fit").unwrap();
+    let last_data_byte = data.len() - 3;
+    data[last_data_byte] ^= 0xFF;
+    match fit::verify(&data) {
+        Err(fit::FitError::CrcMismatch { .. }) => (),
+        r => panic!("expected a crc mismatch, got {:?}", r),
+    }
+
+    let mut lenient = fit::Fit::from_bytes(&data).unwrap();
+    assert!(lenient.results().all(|r| r.is_ok()));
+    assert_eq!(lenient.crc_valid(), Some(false));
+
+    let mut strict = fit::Fit::from_bytes(&data)
+        .unwrap()
+        .options(fit::DecodeOptions::new().strict(true));
+    // data that's all in memory is refused before any of it is decoded
+    let results: Vec<_> = strict.results().collect();
+    match &results[..] {
+        [Err(fit::FitError::CrcMismatch { .. })] => (),
+        r => panic!("expected only a crc mismatch, got {:?}", r),
+    }
 
+    // while a reader can only be checked once it's been read to the end
+    let mut strict = fit::Fit::from_reader(&data[..])
+        .unwrap()
+        .options(fit::DecodeOptions::new().strict(true));
+    let results: Vec<_> = strict.results().collect();
+    assert!(results.len() > 1);
+    match results.last() {
+        Some(Err(fit::FitError::CrcMismatch { .. })) => (),
+        r => panic!("expected a crc mismatch, got {:?}", r),
+    }
+}
+
+#[test]
+fn it_rejects_corrupted_headers_in_strict_mode() {
+    let mut data = std::fs::read("data/garmin_1000.fit").unwrap();
+    data[1] ^= 0xFF;
+    let mut f = fit::Fit::from_bytes(&data)
+        .unwrap()
+        .options(fit::DecodeOptions::new().strict(true));
+    assert_eq!(f.file_header().crc_valid(), Some(false));
+    let results: Vec<_> = f.results().collect();
+    assert_eq!(results.len(), 1);
+    match &results[0] {
+        Err(fit::FitError::CrcMismatch { .. }) => (),
+        r => panic!("expected a crc mismatch, got {:?}", r),
+    }
+}
+
+#[test]
+fn it_reads_legacy_headers() {
+    let data = std::fs::read("data/garmin_1000.fit").unwrap();
+    let expected: Vec<_> = fit::Fit::from_bytes(&data)
+        .unwrap()
+        .map(|m| m.kind)
+        .collect();
+
+    // rewrite the file with a 12 byte header, which has no CRC
+    let mut legacy = vec![12];
+    legacy.extend_from_slice(&data[1..12]);
+    legacy.extend_from_slice(&data[14..]);
+    let f = fit::Fit::from_bytes(&legacy).unwrap();
+    assert!(!f.file_header().has_crc());
+    let decoded: Vec<_> = f.map(|m| m.kind).collect();
+    assert_eq!(decoded, expected);
+}
+
+#[test]
+fn it_reads_chained_files() {
+    let first = std::fs::read("data/wahoo_elemnt.fit").unwrap();
+    let second = std::fs::read("data/garmin_1000.fit").unwrap();
+    let first_kinds: Vec<_> = fit::Fit::from_bytes(&first)
+        .unwrap()
+        .map(|m| m.kind)
+        .collect();
+    let second_kinds: Vec<_> = fit::Fit::from_bytes(&second)
+        .unwrap()
+        .map(|m| m.kind)
+        .collect();
+    let mut data = first.clone();
+    data.extend_from_slice(&second);
+    assert!(fit::verify(&data).is_ok());
+
+    let mut f = fit::Fit::from_bytes(&data).unwrap();
+    let messages: Vec<_> = f.results().map(|r| r.unwrap()).collect();
+    assert_eq!(f.file_headers().len(), 2);
+    assert_eq!(f.crc_valid(), Some(true));
+    let (a, b): (Vec<_>, Vec<_>) = messages.into_iter().partition(|m| m.segment == 0);
+    assert_eq!(
+        a.into_iter().map(|m| m.kind).collect::<Vec<_>>(),
+        first_kinds
+    );
+    assert_eq!(
+        b.into_iter().map(|m| m.kind).collect::<Vec<_>>(),
+        second_kinds
+    );
+
+    let mut stream = fit::StreamDecoder::new();
+    let streamed = data
+        .chunks(64)
+        .flat_map(|c| stream.feed(c).collect::<Vec<_>>())
+        .filter(|r| r.as_ref().map(|m| m.segment == 1).unwrap_or(false))
+        .count();
+    assert_eq!(stream.file_headers().len(), 2);
+    assert!(stream.is_finished());
+    assert_eq!(streamed, second_kinds.len());
+}
+
+#[test]
+fn it_expands_compressed_timestamps() {
+    let timestamps: Vec<_> = fit::Fit::open("data/compressed_timestamps.fit")
+        .unwrap()
+        .filter(|m| m.kind == fitsdk::MessageType::Record)
+        .map(|m| {
+            let t = m.values.iter().find(|v| v.field_num == 253).unwrap();
+            t.value.clone()
+        })
+        .collect();
+    let expected: Vec<_> = [0, 2, 7, 7, 100, 101]
+        .iter()
+        .map(|s| fit::Value::Time(1_000_000_028 + s))
+        .collect();
+    assert_eq!(timestamps, expected);
+}
+
+#[test]
+fn it_keeps_one_definition_per_local_message() {
+    let mut f = fit::Fit::open("data/garmin_520_long.fit").unwrap();
+    assert!(f.definitions().iter().all(Option::is_none));
+    assert!(f.results().all(|r| r.is_ok()));
+    assert_eq!(f.definitions().len(), 16);
+    assert!(f.definitions().iter().any(Option::is_some));
+}
+
+#[test]
+fn it_decodes_developer_fields() {
+    let charges: Vec<_> = fit::Fit::open("data/wahoo_elemnt_dev_fields.fit")
+        .unwrap()
+        .filter_map(|m| m.dev_values)
+        .flatten()
+        .map(|d| {
+            assert_eq!((d.data_index, d.field_num), (1, 0));
+            assert_eq!((d.name.as_str(), d.units.as_deref()), ("charge", Some("%")));
+            d.value
+        })
+        .collect();
+    let expected: Vec<_> = (87..=100).rev().map(fit::Value::U8).collect();
+    assert_eq!(charges, expected);
+}
+
+#[test]
+fn it_registers_developer_data_ids() {
+    let mut f = fit::Fit::open("data/wahoo_elemnt_dev_fields.fit").unwrap();
+    assert!(f.developer_data_id(1).is_none());
+    assert!(f.results().all(|r| r.is_ok()));
+    for i in 0..2 {
+        let d: &fit::DeveloperDataId = f.developer_data_id(i).unwrap();
+        assert_eq!(d.developer_data_index, i);
+        assert_eq!(d.application_uuid(), None);
+    }
+    assert!(f.developer_data_id(2).is_none());
+}
+
+#[test]
+fn it_scales_developer_fields() {
+    let mut f = fit::Fit::open("data/developer_native_fields.fit").unwrap();
+    let records: Vec<_> = f
+        .results()
+        .map(|r| r.unwrap())
+        .filter(|m| m.kind == fitsdk::MessageType::Record)
+        .collect();
+    let powers: Vec<_> = records
+        .iter()
+        .map(|m| {
+            let d = &m.dev_values.as_ref().unwrap()[0];
+            assert_eq!(
+                (d.name.as_str(), d.units.as_deref()),
+                ("Power", Some("watts"))
+            );
+            d.value.clone()
+        })
+        .collect();
+    assert_eq!(powers, vec![fit::Value::F64(250.0), fit::Value::F64(250.5)]);
+    assert!(records
+        .iter()
+        .all(|m| m.values.iter().all(|v| v.field_num != 7)));
+
+    let d = f.developer_data_id(0).unwrap();
+    assert_eq!(d.application_version, Some(12));
+    assert_eq!(
+        d.application_uuid().as_deref(),
+        Some("00010203-0405-0607-0809-0a0b0c0d0e0f")
+    );
+}
+
+#[test]
+fn it_maps_developer_fields_to_native_fields() {
+    let powers: Vec<_> = fit::Fit::open("data/developer_native_fields.fit")
+        .unwrap()
+        .options(fit::DecodeOptions::new().native_developer_fields(true))
+        .filter(|m| m.kind == fitsdk::MessageType::Record)
+        .map(|m| {
+            let power = m.values.iter().find(|v| v.field_num == 7).unwrap();
+            power.value.clone()
+        })
+        .collect();
+    assert_eq!(powers, vec![fit::Value::F64(250.0), fit::Value::F64(250.5)]);
+}
+
+#[test]
+fn it_names_fields_from_the_profile() {
+    let m = fit::Fit::open("data/compressed_timestamps.fit")
+        .unwrap()
+        .find(|m| m.kind == fitsdk::MessageType::Record)
+        .unwrap();
+    assert_eq!(m.get("heart_rate"), Some(&fit::Value::U8(100)));
+    let f = m.field_by_name("timestamp").unwrap();
+    assert_eq!((f.field_num, f.units), (253, Some("s")));
+    assert_eq!(m.get("power"), None);
+}
+
+#[test]
+fn it_resolves_subfields() {
+    let messages: Vec<_> = fit::Fit::open("data/garmin_1000.fit").unwrap().collect();
+    let file_id = messages
+        .iter()
+        .find(|m| m.kind == fitsdk::MessageType::FileId)
+        .unwrap();
+    match file_id.get("garmin_product") {
+        Some(fit::Value::Enum(_)) => (),
+        v => panic!("expected a garmin product, got {:?}", v),
+    }
+    assert_eq!(file_id.get("product"), file_id.get("garmin_product"));
+    let product = file_id.field_by_name("product").unwrap();
+    assert_eq!(product.subfield, Some("garmin_product"));
+
+    let events: Vec<_> = messages
+        .iter()
+        .filter(|m| m.kind == fitsdk::MessageType::Event)
+        .collect();
+    assert_eq!(
+        events[0].get("timer_trigger"),
+        Some(&fit::Value::Enum("manual"))
+    );
+    assert_eq!(events[0].get("data"), Some(&fit::Value::Enum("manual")));
+    let gears: Vec<_> = ["rear_gear_num", "rear_gear", "front_gear_num", "front_gear"]
+        .iter()
+        .map(|name| events[1].get(name).cloned())
+        .collect();
+    assert!(events[1].get("gear_change_data").is_some());
+    assert_eq!(
+        gears,
+        vec![
+            Some(fit::Value::U8(6)),
+            Some(fit::Value::U8(16)),
+            Some(fit::Value::U8(2)),
+            Some(fit::Value::U8(53)),
+        ]
+    );
+}
+
+#[test]
+fn it_accumulates_compressed_distances() {
+    let data = std::fs::read("data/compressed_speed_distance.fit").unwrap();
+    let mut chained = data.clone();
+    chained.extend_from_slice(&data);
+    let distances: Vec<_> = fit::Fit::from_bytes(&chained)
+        .unwrap()
+        .filter(|m| m.kind == fitsdk::MessageType::Record)
+        .map(|m| (m.segment, m.get("distance").cloned().unwrap()))
+        .collect();
+    // the counter wraps around every 256m, and starts again for the second file
+    let expected: Vec<_> = [0, 1]
+        .iter()
+        .flat_map(|s| {
+            [250.0, 255.75, 256.75, 262.25]
+                .iter()
+                .map(move |d| (*s, fit::Value::F64(*d)))
+        })
+        .collect();
+    assert_eq!(distances, expected);
+}
+
+#[test]
+fn it_expands_the_speed_of_compressed_records() {
+    let records: Vec<_> = fit::Fit::open("data/compressed_speed_distance.fit")
+        .unwrap()
+        .filter(|m| m.kind == fitsdk::MessageType::Record)
+        .collect();
+    assert!(!records.is_empty());
+    for r in records {
+        // speed is unpacked from compressed_speed_distance, then enhanced_speed from speed
+        assert!(r.get("speed").is_some());
+        assert_eq!(r.get("enhanced_speed"), r.get("speed"));
+        let field = r.values.iter().find(|v| v.field_num == 73).unwrap();
+        assert_eq!(field.name, Some("enhanced_speed"));
+    }
+}
+
+#[test]
+fn it_scales_fields_from_the_profile() {
+    let messages: Vec<_> = fit::Fit::open("data/garmin_1000.fit").unwrap().collect();
+    let approx = |v: Option<&fit::Value>, expected: f64| match v {
+        Some(fit::Value::F64(v)) => assert!((v - expected).abs() < 1e-9, "{}", v),
+        v => panic!("expected {} as a scaled value, got {:?}", expected, v),
+    };
+
+    let record = messages
+        .iter()
+        .filter(|m| m.kind == fitsdk::MessageType::Record)
+        .nth(100)
+        .unwrap();
+    approx(record.get("distance"), 257.07);
+    approx(record.get("speed"), 3.91);
+    approx(record.get("enhanced_speed"), 3.91);
+    approx(record.get("altitude"), -22.4);
+    approx(record.get("enhanced_altitude"), -22.4);
+
+    let session = messages
+        .iter()
+        .find(|m| m.kind == fitsdk::MessageType::Session)
+        .unwrap();
+    approx(session.get("total_elapsed_time"), 5484.45);
+    approx(session.get("total_timer_time"), 5163.025);
+    approx(session.get("total_distance"), 28432.44);
+    approx(session.get("avg_speed"), 5.507);
+}
+
+#[test]
+fn it_decodes_with_options() {
+    let record = |options: fit::DecodeOptions| {
+        fit::Fit::open("data/garmin_1000.fit")
+            .unwrap()
+            .options(options)
+            .filter(|m| m.kind == fitsdk::MessageType::Record)
+            .nth(100)
+            .unwrap()
+    };
+
+    let raw = record(fit::DecodeOptions::new().scale(false).coordinates(false));
+    assert_eq!(raw.get("distance"), Some(&fit::Value::U32(25707)));
+    assert_eq!(raw.get("altitude"), Some(&fit::Value::U16(2388)));
+    assert_eq!(raw.get("position_lat"), Some(&fit::Value::I32(688508200)));
+
+    let unexpanded = record(fit::DecodeOptions::new().expand_components(false));
+    assert!(unexpanded.get("speed").is_some());
+    assert_eq!(unexpanded.get("enhanced_speed"), None);
+}
+
+#[test]
+fn it_keeps_invalid_values() {
+    let file_id = |options: fit::DecodeOptions| {
+        fit::Fit::open("data/garmin_1000.fit")
+            .unwrap()
+            .options(options)
+            .next()
+            .unwrap()
+    };
+    assert_eq!(file_id(fit::DecodeOptions::new()).get("number"), None);
+    let options = fit::DecodeOptions::new().invalid_values(true);
+    assert_eq!(file_id(options).get("number"), Some(&fit::Value::Invalid));
+}
+
+#[test]
+fn it_yields_unknown_messages() {
+    let unknown = |options: fit::DecodeOptions| {
+        fit::Fit::open("data/garmin_1000.fit")
+            .unwrap()
+            .options(options)
+            .filter(|m| m.kind == fitsdk::MessageType::None)
+            .count()
+    };
+    assert_eq!(unknown(fit::DecodeOptions::new()), 0);
+    assert!(unknown(fit::DecodeOptions::new().unknown_messages(true)) > 0);
+}
+
+#[test]
+fn it_yields_manufacturer_specific_messages() {
+    let options = fit::DecodeOptions::new()
+        .unknown_messages(true)
+        .invalid_values(true);
+    let messages: Vec<_> = fit::Fit::open("data/wahoo_elemnt.fit")
+        .unwrap()
+        .options(options)
+        .filter(|m| m.is_manufacturer_specific())
+        .collect();
+    let count = |n| {
+        messages
+            .iter()
+            .filter(|m| m.global_message_number == n)
+            .count()
+    };
+    assert_eq!((count(0xFF00), count(0xFF01)), (43, 2));
+
+    // their values are decoded by base type alone
+    let m = messages
+        .iter()
+        .find(|m| m.global_message_number == 0xFF01)
+        .unwrap();
+    assert_eq!(m.kind, fitsdk::MessageType::None);
+    let value = |n| m.values.iter().find(|v| v.field_num == n).map(|v| &v.value);
+    assert_eq!(
+        value(0),
+        Some(&fit::Value::String("ELEMNT BOLT FE0B".to_string()))
+    );
+    assert_eq!(value(2), Some(&fit::Value::U16(0)));
+    assert!(m.values.iter().all(|v| v.name.is_none()));
+}
+
+#[test]
+fn it_reads_raw_records() {
+    let mut f = fit::Fit::open("data/garmin_1000.fit").unwrap();
+    let records: Vec<_> = f.records().map(|r| r.unwrap()).collect();
+    assert_eq!(records.len(), 5248);
+    let definitions = records
+        .iter()
+        .filter(|r| matches!(r, fit::Record::Definition { .. }))
+        .count();
+    assert_eq!(definitions, 26);
+
+    match &records[0] {
+        fit::Record::Definition {
+            local_num,
+            offset,
+            definition,
+        } => {
+            assert_eq!((*local_num, *offset), (0, 14));
+            assert_eq!(definition.global_message_number, 0);
+            assert_eq!(definition.field_definitions.len(), 7);
+        }
+        r => panic!("expected a definition record, got {:?}", r),
+    }
+    match &records[1] {
+        fit::Record::Data {
+            header,
+            offset,
+            raw_bytes,
+            fields,
+            ..
+        } => {
+            assert!(!header.definition && !header.compressed_header);
+            assert_eq!(*offset, 41);
+            assert_eq!(raw_bytes.len(), 19);
+            assert_eq!(records[2].offset(), 41 + 1 + 19);
+            // the time created field isn't turned into a timestamp
+            assert_eq!(fields[1].field_num, 4);
+            assert_eq!(fields[1].value, fit::Value::U32(849790468));
+            assert!(fields.iter().all(|f| f.name.is_none()));
+        }
+        r => panic!("expected a data record, got {:?}", r),
+    }
+}
+
+#[test]
+fn it_converts_messages_to_typed_structs() {
+    use fit::messages::{FileId, Record, Session};
+    use std::convert::TryFrom;
+
+    let messages: Vec<_> = fit::Fit::open("data/garmin_1000.fit").unwrap().collect();
+    let file_id = FileId::try_from(&messages[0]).unwrap();
+    assert_eq!(file_id.r#type.as_deref(), Some("activity"));
+    assert_eq!(file_id.manufacturer.as_deref(), Some("garmin"));
+    assert_eq!(file_id.serial_number, Some(3902378567));
+    assert_eq!(file_id.number, None);
+    assert_eq!(file_id.product, Some(fit::Value::Enum("edge1000")));
+    assert_eq!(file_id.garmin_product.as_deref(), Some("edge1000"));
+    assert_eq!(file_id.favero_product, None);
+
+    let records: Vec<_> = messages
+        .iter()
+        .filter_map(|m| Record::try_from(m).ok())
+        .collect();
+    assert_eq!(records[100].heart_rate, Some(128));
+    assert!((records[100].distance.unwrap() - 257.07).abs() < 1e-9);
+    assert!((records[100].enhanced_altitude.unwrap() + 22.4).abs() < 1e-9);
+
+    let session = messages
+        .iter()
+        .find_map(|m| Session::try_from(m).ok())
+        .unwrap();
+    assert!((session.total_distance.unwrap() - 28432.44).abs() < 1e-9);
+
+    match Session::try_from(&messages[0]) {
+        Err(fit::FitError::UnexpectedMessage { expected, found }) => {
+            assert_eq!(expected, fitsdk::MessageType::Session);
+            assert_eq!(found, fitsdk::MessageType::FileId);
+        }
+        r => panic!("expected an unexpected message error, got {:?}", r),
+    }
+}
+
+#[test]
+fn it_leaves_unscaled_quantities_out_of_typed_structs() {
+    use fit::messages::Record;
+    use std::convert::TryFrom;
+
+    let options = fit::DecodeOptions::new().scale(false).coordinates(false);
+    let records: Vec<_> = fit::Fit::open("data/garmin_1000.fit")
+        .unwrap()
+        .options(options)
+        .filter_map(|m| Record::try_from(&m).ok())
+        .collect();
+    assert_eq!(records[100].heart_rate, Some(128));
+    assert_eq!(records[100].distance, None);
+    assert_eq!(records[100].position_lat, None);
+}
+
+#[test]
+fn it_takes_the_utc_offset_from_the_activity() {
+    use std::convert::TryFrom;
+
+    let mut f = fit::Fit::open("data/garmin_520_long.fit").unwrap();
+    assert_eq!(f.utc_offset(), None);
+    let activity = f
+        .by_ref()
+        .find(|m| m.kind == fitsdk::MessageType::Activity)
+        .unwrap();
+    assert_eq!(f.utc_offset(), Some(7200));
+
+    let local = match activity.get("local_timestamp") {
+        Some(fit::Value::LocalTime(t)) => *t,
+        v => panic!("expected a local time, got {:?}", v),
+    };
+    assert_eq!(local.utc_offset, Some(7200));
+    assert_eq!(
+        local.to_utc(),
+        activity
+            .get("timestamp")
+            .and_then(|t| fit::Timestamp::try_from(t).ok())
+    );
+}
 
 fn read_file(f: PathBuf) {
     let f = fit::Fit::new(&f);